@@ -2,6 +2,7 @@ mod astronomy;
 mod astronomy_current;
 mod astronomy_day;
 mod astronomy_day_event;
+mod astronomy_day_event_type;
 mod astronomy_details;
 mod astronomy_event;
 mod astronomy_event_class;
@@ -16,6 +17,7 @@ pub use astronomy::Astronomy;
 pub use astronomy_current::AstronomyCurrent;
 pub use astronomy_day::AstronomyDay;
 pub use astronomy_day_event::AstronomyDayEvent;
+pub use astronomy_day_event_type::AstronomyDayEventType;
 pub use astronomy_details::AstronomyDetails;
 pub use astronomy_event::AstronomyEvent;
 pub use astronomy_event_class::AstronomyEventClass;
@@ -1,7 +1,7 @@
 use super::AstronomyObject;
 use serde::Deserialize;
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 /// Astronomical information for requested objects.
 pub struct Astronomy {
     /// List of requested objects.
@@ -1,6 +1,7 @@
-use super::{AstronomyDayEvent, MoonPhase};
+use super::{AstronomyDayEvent, AstronomyDayEventType, MoonPhase};
 use crate::time::DateTime;
-use serde::Deserialize;
+use chrono::Duration;
+use serde::{de, Deserialize, Deserializer};
 
 #[derive(Debug, Deserialize)]
 /// Information about an astronomical object for a specific day.
@@ -9,10 +10,11 @@ pub struct AstronomyDay {
     /// Date for the current information.
     pub date: DateTime,
 
+    #[serde(deserialize_with = "deserialize_daylength", default)]
     /// Length of this day (time between sunrise and sunset). If the sun is not up on this day,
-    /// 00:00:00 will be reported. If the sun does not set on this day, the value will read
-    /// 24:00:00. Attribute only applies for the sun object and if requested.
-    pub daylength: Option<String>,
+    /// zero will be reported. If the sun does not set on this day, the value will be 24 hours.
+    /// Attribute only applies for the sun object and if requested.
+    pub daylength: Option<Duration>,
 
     /// Moon phase for the day. Only if requested.
     pub moonphase: Option<MoonPhase>,
@@ -20,3 +22,96 @@ pub struct AstronomyDay {
     /// Lists all events during the day.
     pub events: Vec<AstronomyDayEvent>,
 }
+
+impl AstronomyDay {
+    /// Whether the sun stays up all day, i.e. it neither rises nor sets and the day length is
+    /// 24 hours.
+    pub fn is_polar_day(&self) -> bool {
+        self.is_polar(Duration::hours(24))
+    }
+
+    /// Whether the sun stays down all day, i.e. it neither rises nor sets and the day length is
+    /// zero.
+    pub fn is_polar_night(&self) -> bool {
+        self.is_polar(Duration::zero())
+    }
+
+    fn is_polar(&self, daylength: Duration) -> bool {
+        self.daylength == Some(daylength) && self.sunrise().is_none() && self.sunset().is_none()
+    }
+
+    /// First event of the given type during the day.
+    pub fn event(&self, r#type: &AstronomyDayEventType) -> Option<&AstronomyDayEvent> {
+        self.events.iter().find(|e| e.r#type == *r#type)
+    }
+
+    /// Rise of the object. For the sun, this is sunrise.
+    pub fn sunrise(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::Rise)
+    }
+
+    /// Set of the object. For the sun, this is sunset.
+    pub fn sunset(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::Set)
+    }
+
+    /// Meridian passing of the object. For the sun, this is solar noon.
+    pub fn solar_noon(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::Meridian)
+    }
+
+    /// Start of civil twilight in the morning.
+    pub fn civil_dawn(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::CivilStart)
+    }
+
+    /// End of civil twilight in the evening.
+    pub fn civil_dusk(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::CivilEnd)
+    }
+
+    /// Start of nautical twilight in the morning.
+    pub fn nautical_dawn(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::NauticalStart)
+    }
+
+    /// End of nautical twilight in the evening.
+    pub fn nautical_dusk(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::NauticalEnd)
+    }
+
+    /// Start of astronomical twilight in the morning.
+    pub fn astronomical_dawn(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::AstronomicalStart)
+    }
+
+    /// End of astronomical twilight in the evening.
+    pub fn astronomical_dusk(&self) -> Option<&AstronomyDayEvent> {
+        self.event(&AstronomyDayEventType::AstronomicalEnd)
+    }
+}
+
+/// Parse a day length in the format "hh:mm:ss".
+fn deserialize_daylength<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Deserialize::deserialize(deserializer)?;
+
+    value
+        .map(|value| {
+            let components: Vec<i64> = value
+                .split(':')
+                .map(|c| c.parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| de::Error::custom(format!("invalid day length: {}", value)))?;
+
+            match components.as_slice() {
+                [h, m, s] => {
+                    Ok(Duration::hours(*h) + Duration::minutes(*m) + Duration::seconds(*s))
+                }
+                _ => Err(de::Error::custom(format!("invalid day length: {}", value))),
+            }
+        })
+        .transpose()
+}
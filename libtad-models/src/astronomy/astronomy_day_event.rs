@@ -1,11 +1,14 @@
+use super::AstronomyDayEventType;
 use crate::time::DateTime;
+use chrono::{FixedOffset, NaiveTime};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
+#[serde(from = "RawAstronomyDayEvent")]
 /// Information about an astronomical event at a specific day.
 pub struct AstronomyDayEvent {
     /// Indicates the type of the event.
-    pub r#type: String,
+    pub r#type: AstronomyDayEventType,
 
     /// Hour at which the event is happening (local time).
     pub hour: i32,
@@ -16,12 +19,14 @@ pub struct AstronomyDayEvent {
     /// Second at which the event is happening (local time).
     pub sec: i32,
 
-    #[serde(deserialize_with = "DateTime::option_deserialize_from_str", default)]
     /// Local time at which the event is happening in ISO 8601 format.
     /// Only returned if requested by specifying the parameter isotime.
     pub isotime: Option<DateTime>,
 
-    #[serde(deserialize_with = "DateTime::option_deserialize_from_str", default)]
+    /// UTC offset of the local time, as given in the ISO 8601 representation of isotime.
+    /// Only returned if requested by specifying the parameter isotime.
+    pub offset: Option<FixedOffset>,
+
     /// UTC time at which the event is happening in ISO 8601 format.
     /// Only returned if requested by specifying the parameter utctime.
     pub utctime: Option<DateTime>,
@@ -47,3 +52,54 @@ pub struct AstronomyDayEvent {
     /// selected location. Only for the moon for meridian type events.
     pub posangle: Option<f32>,
 }
+
+#[derive(Deserialize)]
+struct RawAstronomyDayEvent {
+    r#type: AstronomyDayEventType,
+    hour: i32,
+    min: i32,
+    sec: i32,
+    isotime: Option<String>,
+    #[serde(deserialize_with = "DateTime::option_deserialize_from_str", default)]
+    utctime: Option<DateTime>,
+    altitude: Option<f32>,
+    azimuth: Option<f32>,
+    distance: Option<f32>,
+    illuminated: Option<f32>,
+    posangle: Option<f32>,
+}
+
+impl From<RawAstronomyDayEvent> for AstronomyDayEvent {
+    fn from(raw: RawAstronomyDayEvent) -> Self {
+        let offset = raw
+            .isotime
+            .as_deref()
+            .and_then(|iso| chrono::DateTime::parse_from_rfc3339(iso).ok())
+            .map(|datetime| *datetime.offset());
+
+        Self {
+            r#type: raw.r#type,
+            hour: raw.hour,
+            min: raw.min,
+            sec: raw.sec,
+            isotime: raw
+                .isotime
+                .as_deref()
+                .map(|iso| DateTime::from(iso.get(..19).unwrap_or(iso))),
+            offset,
+            utctime: raw.utctime,
+            altitude: raw.altitude,
+            azimuth: raw.azimuth,
+            distance: raw.distance,
+            illuminated: raw.illuminated,
+            posangle: raw.posangle,
+        }
+    }
+}
+
+impl AstronomyDayEvent {
+    /// Local time of day at which the event is happening.
+    pub fn time(&self) -> Option<NaiveTime> {
+        NaiveTime::from_hms_opt(self.hour as u32, self.min as u32, self.sec as u32)
+    }
+}
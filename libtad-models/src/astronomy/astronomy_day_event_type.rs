@@ -0,0 +1,102 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "String")]
+/// Kind of an astronomical event during a day.
+pub enum AstronomyDayEventType {
+    /// The object rises above the horizon.
+    Rise,
+
+    /// The object sets below the horizon.
+    Set,
+
+    /// The object passes the meridian, its highest point.
+    Meridian,
+
+    /// The object passes the anti-meridian, its lowest point.
+    AntiMeridian,
+
+    /// Civil twilight (-6°) starts.
+    CivilStart,
+
+    /// Civil twilight (-6°) ends.
+    CivilEnd,
+
+    /// Nautical twilight (-12°) starts.
+    NauticalStart,
+
+    /// Nautical twilight (-12°) ends.
+    NauticalEnd,
+
+    /// Astronomical twilight (-18°) starts.
+    AstronomicalStart,
+
+    /// Astronomical twilight (-18°) ends.
+    AstronomicalEnd,
+
+    /// New moon.
+    NewMoon,
+
+    /// Moon in first quarter.
+    FirstQuarter,
+
+    /// Full moon.
+    FullMoon,
+
+    /// Moon in third quarter.
+    ThirdQuarter,
+
+    /// Event type not known to this library.
+    Unknown(String),
+}
+
+impl AstronomyDayEventType {
+    /// Name of the event type used by the API, e.g. "civil_start".
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Rise => "rise",
+            Self::Set => "set",
+            Self::Meridian => "meridian",
+            Self::AntiMeridian => "antimeridian",
+            Self::CivilStart => "civil_start",
+            Self::CivilEnd => "civil_end",
+            Self::NauticalStart => "nautical_start",
+            Self::NauticalEnd => "nautical_end",
+            Self::AstronomicalStart => "astronomical_start",
+            Self::AstronomicalEnd => "astronomical_end",
+            Self::NewMoon => "newmoon",
+            Self::FirstQuarter => "firstquarter",
+            Self::FullMoon => "fullmoon",
+            Self::ThirdQuarter => "thirdquarter",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl From<String> for AstronomyDayEventType {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "rise" => Self::Rise,
+            "set" => Self::Set,
+            "meridian" => Self::Meridian,
+            "antimeridian" => Self::AntiMeridian,
+            "civil_start" => Self::CivilStart,
+            "civil_end" => Self::CivilEnd,
+            "nautical_start" => Self::NauticalStart,
+            "nautical_end" => Self::NauticalEnd,
+            "astronomical_start" => Self::AstronomicalStart,
+            "astronomical_end" => Self::AstronomicalEnd,
+            "newmoon" => Self::NewMoon,
+            "firstquarter" => Self::FirstQuarter,
+            "fullmoon" => Self::FullMoon,
+            "thirdquarter" => Self::ThirdQuarter,
+            _ => Self::Unknown(s),
+        }
+    }
+}
+
+impl std::fmt::Display for AstronomyDayEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -1,7 +1,7 @@
 use super::AstronomyEventType;
 use serde::Deserialize;
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 /// Information about a sunrise/sunset event for a specific day.
 pub struct AstronomyEvent {
     /// Indicates the type of the event.
@@ -1,4 +1,8 @@
-use crate::{astronomy::AstronomyDetails, places::Geo};
+use crate::{
+    astronomy::{AstronomyDay, AstronomyDetails, AstronomyObjectType},
+    places::Geo,
+};
+use chrono::Duration;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -16,3 +20,64 @@ pub struct AstronomyLocation {
     /// Requested astronomical information.
     pub astronomy: AstronomyDetails,
 }
+
+impl AstronomyLocation {
+    /// Days returned for the sun, if requested.
+    pub fn sun_days(&self) -> &[AstronomyDay] {
+        self.astronomy
+            .objects
+            .iter()
+            .find(|object| object.name == AstronomyObjectType::Sun)
+            .and_then(|object| object.days.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Day with the longest day length in the returned range. The first is returned on ties.
+    pub fn longest_day(&self) -> Option<&AstronomyDay> {
+        self.days_with_daylength()
+            .rev()
+            .max_by_key(|(_, daylength)| *daylength)
+            .map(|(day, _)| day)
+    }
+
+    /// Day with the shortest day length in the returned range. The first is returned on ties.
+    pub fn shortest_day(&self) -> Option<&AstronomyDay> {
+        self.days_with_daylength()
+            .min_by_key(|(_, daylength)| *daylength)
+            .map(|(day, _)| day)
+    }
+
+    /// Change in day length from the previous day, for every day after the first. Days without
+    /// a day length, and the days following them, are skipped.
+    pub fn daylength_deltas(&self) -> Vec<(&AstronomyDay, Duration)> {
+        self.sun_days()
+            .windows(2)
+            .filter_map(|pair| match (pair[0].daylength, pair[1].daylength) {
+                (Some(previous), Some(current)) => Some((&pair[1], current - previous)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Days on which the sun does not set.
+    pub fn polar_days(&self) -> Vec<&AstronomyDay> {
+        self.sun_days()
+            .iter()
+            .filter(|d| d.is_polar_day())
+            .collect()
+    }
+
+    /// Days on which the sun does not rise.
+    pub fn polar_nights(&self) -> Vec<&AstronomyDay> {
+        self.sun_days()
+            .iter()
+            .filter(|d| d.is_polar_night())
+            .collect()
+    }
+
+    fn days_with_daylength(&self) -> impl DoubleEndedIterator<Item = (&AstronomyDay, Duration)> {
+        self.sun_days()
+            .iter()
+            .filter_map(|day| day.daylength.map(|daylength| (day, daylength)))
+    }
+}
@@ -1,7 +1,7 @@
 use super::{AstronomyEvent, AstronomyObjectType};
 use serde::Deserialize;
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 /// Astronomical information - sunrise and sunset times.
 pub struct AstronomyObject {
     /// Object name. Currently, the sun is the only supported astronomical object.
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+/// A distance, stored in kilometers as returned by the API.
+pub struct Distance {
+    km: f32,
+}
+
+impl Distance {
+    const KM_PER_MILE: f32 = 1.609_344;
+
+    /// Distance in kilometers.
+    pub fn from_km(km: f32) -> Self {
+        Self { km }
+    }
+
+    /// Distance in statute miles.
+    pub fn from_miles(miles: f32) -> Self {
+        Self {
+            km: miles * Self::KM_PER_MILE,
+        }
+    }
+
+    /// The distance in kilometers.
+    pub fn km(&self) -> f32 {
+        self.km
+    }
+
+    /// The distance in statute miles.
+    pub fn miles(&self) -> f32 {
+        self.km / Self::KM_PER_MILE
+    }
+}
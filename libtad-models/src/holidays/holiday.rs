@@ -1,4 +1,4 @@
-use super::HolidayState;
+use super::{HolidayState, HolidayType};
 use crate::{places::Country, time::Time, Text};
 use serde::Deserialize;
 
@@ -59,3 +59,21 @@ pub struct Holiday {
     /// Example: National Holiday
     pub types: Option<Vec<String>>,
 }
+
+impl Holiday {
+    /// Holiday types of the classification labels in `types`. Unknown labels are skipped.
+    pub fn categories(&self) -> Vec<HolidayType> {
+        self.types
+            .iter()
+            .flatten()
+            .filter_map(|label| HolidayType::from_label(label))
+            .collect()
+    }
+
+    /// Whether the holiday is classified as a type, or as a type included in it.
+    pub fn is_type(&self, holiday_type: HolidayType) -> bool {
+        self.categories()
+            .into_iter()
+            .any(|category| holiday_type.includes(category))
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 /// All valid holiday types.
 pub enum HolidayType {
@@ -106,3 +106,79 @@ pub enum HolidayType {
     /// Fun, Wacky and Trivial holidays.
     Fun,
 }
+
+impl HolidayType {
+    /// Holiday type of a classification label returned in `Holiday::types`, e.g. "National
+    /// holiday" or "Half Day". Labels are compared as a whole, case-insensitively, ignoring spaces,
+    /// punctuation and a trailing "holiday". Returns `None` for unknown labels.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let words: Vec<String> = label
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        let words = match words.split_last() {
+            Some((last, rest)) if !rest.is_empty() && (last == "holiday" || last == "holidays") => {
+                rest
+            }
+            _ => &words[..],
+        };
+
+        let holiday_type = match words.concat().as_str() {
+            "halfday" => Self::HalfDay,
+            "commonlocal" => Self::FederalLocal,
+            "localobservance" => Self::Local2,
+            "local" | "state" | "regional" | "provincial" => Self::Local,
+            "national" | "federal" | "public" | "statutory" | "bank" => Self::Federal,
+            "defacto" => Self::Defacto,
+            "flagday" => Self::FlagDay,
+            "optional" | "restricted" => Self::Optional,
+            "workingday" => Self::Weekday,
+            "season" | "seasons" | "equinox" | "solstice" => Self::Seasons,
+            "orthodox" => Self::Orthodox,
+            "christian" => Self::Christian,
+            "muslim" => Self::Muslim,
+            "hebrew" | "jewish" => Self::Hebrew,
+            "hindu" | "hinduism" => Self::Hinduism,
+            "buddhist" | "buddhism" => Self::Buddhism,
+            "religious" | "otherreligion" => Self::OtherReligion,
+            "sport" | "sports" | "sportingevent" => Self::Sport,
+            "clockchange" | "daylightsavingtime" | "clockchangedaylightsavingtime" => Self::TZ,
+            "unitednations" | "unitednationsobservance" => Self::UN,
+            "worldwide" | "worldwideobservance" => Self::World,
+            "fun" => Self::Fun,
+            "observance" => Self::Obs,
+            _ => return None,
+        };
+
+        Some(holiday_type)
+    }
+
+    /// Whether holidays of another type are part of this type. Combined types like `All` and
+    /// `Religious` include the types they combine, and every type includes itself.
+    pub fn includes(&self, other: HolidayType) -> bool {
+        use HolidayType::*;
+
+        match self {
+            All => other != Fun,
+            Default => matches!(other, Default | Federal | FederalLocal | Obs1 | Weekday),
+            CountryDefault => matches!(
+                other,
+                CountryDefault | Default | Federal | FederalLocal | Obs1 | Weekday | Local
+            ),
+            Obs => matches!(other, Obs | Obs1 | Obs2 | Obs3),
+            Religious => matches!(
+                other,
+                Religious
+                    | Buddhism
+                    | Christian
+                    | Hebrew
+                    | Hinduism
+                    | Muslim
+                    | Orthodox
+                    | OtherReligion
+            ),
+            _ => *self == other,
+        }
+    }
+}
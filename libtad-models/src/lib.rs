@@ -3,6 +3,9 @@
 
 //! Types and models in use by Time and Date API services.
 
+mod distance;
+pub use distance::Distance;
+
 mod text;
 pub use text::Text;
 
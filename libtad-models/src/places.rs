@@ -3,6 +3,7 @@ mod geo;
 mod location;
 mod location_ref;
 mod place;
+mod place_id;
 mod region;
 
 pub use country::Country;
@@ -10,4 +11,5 @@ pub use geo::Geo;
 pub use location::Location;
 pub use location_ref::LocationRef;
 pub use place::Place;
+pub use place_id::PlaceId;
 pub use region::Region;
@@ -1,7 +1,8 @@
 use super::Country;
-use serde::Deserialize;
+use crate::Distance;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 /// Geographical information about a location.
 pub struct Geo {
     /// The name of the location.
@@ -28,6 +29,24 @@ pub struct Geo {
     pub longitude: Option<f32>,
 }
 
+impl Geo {
+    /// Mean radius of the earth in kilometers.
+    const EARTH_RADIUS: f32 = 6371.0;
+
+    /// Great-circle distance to a coordinate, if the coordinates of the location are known.
+    pub fn distance_to(&self, latitude: f32, longitude: f32) -> Option<Distance> {
+        let (lat1, lat2) = (self.latitude?.to_radians(), latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (longitude - self.longitude?).to_radians();
+
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+
+        Some(Distance::from_km(
+            2.0 * Self::EARTH_RADIUS * a.sqrt().asin(),
+        ))
+    }
+}
+
 mod custom_deserialize {
     use serde::de::{self, Deserializer, Visitor};
 
@@ -51,6 +70,13 @@ mod custom_deserialize {
                 Ok(None)
             }
 
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(None)
+            }
+
             fn visit_f64<E>(self, s: f64) -> Result<Self::Value, E>
             where
                 E: de::Error,
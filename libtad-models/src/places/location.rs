@@ -5,7 +5,7 @@ use crate::{
 };
 use serde::Deserialize;
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 /// Information about a location.
 pub struct Location {
     /// The id of the location.
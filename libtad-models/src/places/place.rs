@@ -1,7 +1,7 @@
 use super::Geo;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 /// Information about a place.
 pub struct Place {
     /// Numerical ID of the referenced place.
@@ -0,0 +1,78 @@
+use super::Place;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Identifier of a place, accepted by every service which takes a place id.
+///
+/// It converts into the string form expected by the request builders.
+pub enum PlaceId {
+    /// Numerical id of a place.
+    ///
+    /// Example: 187
+    Numeric(i32),
+
+    /// Textual id of a place.
+    ///
+    /// Example: norway/oslo
+    Textual(String),
+
+    /// Coordinates in decimal degrees.
+    ///
+    /// Example: +59.914+10.752
+    Coordinates {
+        /// Geographical latitude.
+        latitude: f32,
+
+        /// Geographical longitude.
+        longitude: f32,
+    },
+}
+
+impl fmt::Display for PlaceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(id) => write!(f, "{}", id),
+            Self::Textual(id) => f.write_str(id),
+            Self::Coordinates {
+                latitude,
+                longitude,
+            } => write!(f, "{:+}{:+}", latitude, longitude),
+        }
+    }
+}
+
+impl From<PlaceId> for String {
+    fn from(id: PlaceId) -> Self {
+        id.to_string()
+    }
+}
+
+impl From<&PlaceId> for String {
+    fn from(id: &PlaceId) -> Self {
+        id.to_string()
+    }
+}
+
+impl From<i32> for PlaceId {
+    fn from(id: i32) -> Self {
+        Self::Numeric(id)
+    }
+}
+
+impl From<&Place> for PlaceId {
+    fn from(place: &Place) -> Self {
+        Self::Numeric(place.id)
+    }
+}
+
+#[test]
+fn format_place_ids() {
+    assert!(String::from(PlaceId::Numeric(187)) == "187");
+    assert!(String::from(PlaceId::Textual("norway/oslo".into())) == "norway/oslo");
+    assert!(
+        String::from(PlaceId::Coordinates {
+            latitude: 59.914,
+            longitude: -10.752
+        }) == "+59.914-10.752"
+    );
+}
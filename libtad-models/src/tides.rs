@@ -1,9 +1,13 @@
 mod station;
 mod station_info;
+mod station_type;
 mod tidal_phase;
 mod tide;
+mod tide_interval;
 
 pub use station::Station;
 pub use station_info::StationInfo;
+pub use station_type::StationType;
 pub use tidal_phase::TidalPhase;
 pub use tide::Tide;
+pub use tide_interval::TideInterval;
@@ -1,3 +1,5 @@
+use super::StationType;
+use crate::Distance;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -13,8 +15,8 @@ pub struct StationInfo {
     pub longitude: f32,
 
     /// Station type. Either reference or subordinate station.
-    pub r#type: String,
+    pub r#type: StationType,
 
     /// Distance between request place and this station.
-    pub distance: f32,
+    pub distance: Distance,
 }
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+/// Type of a tide station.
+pub enum StationType {
+    /// Reference station, with predictions based on its own measurements.
+    Reference,
+
+    /// Subordinate station, with predictions derived from a nearby reference station.
+    Subordinate,
+
+    /// Station type not known to this library.
+    Unknown(String),
+}
+
+impl StationType {
+    /// Short name of the station type, e.g. "reference".
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Reference => "reference",
+            Self::Subordinate => "subordinate",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl From<String> for StationType {
+    fn from(s: String) -> Self {
+        match s.to_lowercase().as_str() {
+            "reference station" | "reference" => Self::Reference,
+            "subordinate station" | "subordinate" => Self::Subordinate,
+            _ => Self::Unknown(s),
+        }
+    }
+}
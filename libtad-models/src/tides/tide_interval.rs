@@ -0,0 +1,49 @@
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Interval between predicted tide data points.
+pub enum TideInterval {
+    /// Every 5 minutes.
+    Minutes5,
+
+    /// Every 15 minutes.
+    Minutes15,
+
+    /// Every 30 minutes.
+    Minutes30,
+
+    /// Every 60 minutes.
+    Minutes60,
+}
+
+impl TideInterval {
+    /// Interval for a number of minutes, if supported.
+    pub fn from_minutes(minutes: u32) -> Option<Self> {
+        match minutes {
+            5 => Some(Self::Minutes5),
+            15 => Some(Self::Minutes15),
+            30 => Some(Self::Minutes30),
+            60 => Some(Self::Minutes60),
+            _ => None,
+        }
+    }
+
+    /// The interval in minutes.
+    pub fn minutes(&self) -> u32 {
+        match self {
+            Self::Minutes5 => 5,
+            Self::Minutes15 => 15,
+            Self::Minutes30 => 30,
+            Self::Minutes60 => 60,
+        }
+    }
+}
+
+impl Serialize for TideInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(self.minutes())
+    }
+}
@@ -1,7 +1,8 @@
 use super::{DateTime, TimeZone};
+use chrono::{FixedOffset, NaiveDateTime, TimeZone as _};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 /// Information about date, time and timezone.
 pub struct Time {
     /// ISO representation of date and time, time zone included
@@ -19,3 +20,19 @@ pub struct Time {
     /// Time zone information.
     pub timezone: Option<TimeZone>,
 }
+
+impl Time {
+    /// Date and time with its offset from UTC, parsed from the ISO representation. Times without
+    /// an offset use the offset of the time zone information. Returns `None` if only the date is
+    /// known, or if the offset is unknown.
+    pub fn local(&self) -> Option<chrono::DateTime<FixedOffset>> {
+        if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(&self.iso) {
+            return Some(datetime);
+        }
+
+        let datetime = NaiveDateTime::parse_from_str(&self.iso, "%Y-%m-%dT%H:%M:%S").ok()?;
+        let offset = self.timezone.as_ref()?.utc_offset()?;
+
+        offset.from_local_datetime(&datetime).single()
+    }
+}
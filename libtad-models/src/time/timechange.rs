@@ -1,7 +1,8 @@
 use super::DateTime;
+use chrono::{FixedOffset, NaiveDateTime, Utc};
 use serde::Deserialize;
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 /// Information about a time change.
 pub struct TimeChange {
     /// New DST offset in seconds. Value will be empty if there is no DST for this location.
@@ -34,7 +35,21 @@ pub struct TimeChange {
     pub verbose: Option<VerboseTimeChange>,
 }
 
-#[derive(Deserialize)]
+impl TimeChange {
+    /// Time stamp of the transition.
+    pub fn utc(&self) -> Option<chrono::DateTime<Utc>> {
+        NaiveDateTime::parse_from_str(&self.utctime, "%Y-%m-%dT%H:%M:%S")
+            .ok()
+            .map(|datetime| datetime.and_utc())
+    }
+
+    /// Total offset from UTC after the transition.
+    pub fn new_offset(&self) -> Option<FixedOffset> {
+        FixedOffset::east_opt(self.newoffset)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
 /// Verbose reprsentation of time change time stamps.
 pub struct VerboseTimeChange {
     /// Time stamp of transition in UTC time, split up into components.
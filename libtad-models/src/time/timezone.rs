@@ -1,6 +1,7 @@
+use chrono::FixedOffset;
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 /// Time zone information.
 pub struct TimeZone {
     /// The time zone offset (from UTC) in string representation.
@@ -31,3 +32,10 @@ pub struct TimeZone {
     /// Example: 39600
     pub zonetotaloffset: i32,
 }
+
+impl TimeZone {
+    /// Total offset from UTC.
+    pub fn utc_offset(&self) -> Option<FixedOffset> {
+        FixedOffset::east_opt(self.zonetotaloffset)
+    }
+}
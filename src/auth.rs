@@ -0,0 +1,43 @@
+use crate::{Credentials, Error};
+use chrono::{DateTime, Utc};
+
+/// Format of the timestamp which is signed and sent with every request.
+pub const TIMESTAMP_FORMAT: &str = "%FT%T";
+
+/// Compute the signature of a request to the service at `path`, made at `timestamp`.
+///
+/// The signature is the base64 encoded HMAC-SHA1 of the access key, the service path and the
+/// timestamp, keyed with the secret key.
+///
+/// Example:
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use libtad_rs::{auth, Credentials};
+///
+/// let credentials = Credentials::new("access", "secret");
+/// let timestamp = Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap();
+///
+/// let signature = auth::sign(&credentials, "holidays", &timestamp).unwrap();
+///
+/// assert!(signature == "AuwQjC5G5pGbmHN6pczY6+8Jw+4=");
+/// ```
+pub fn sign(
+    credentials: &Credentials,
+    path: &str,
+    timestamp: &DateTime<Utc>,
+) -> Result<String, Error> {
+    use hmac::{Hmac, Mac, NewMac};
+    use sha1::Sha1;
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(credentials.secret_key().as_bytes())?;
+
+    let message = format!(
+        "{}{}{}",
+        credentials.access_key(),
+        path,
+        timestamp.format(TIMESTAMP_FORMAT)
+    );
+    mac.update(message.as_bytes());
+
+    Ok(base64::encode(mac.finalize().into_bytes()))
+}
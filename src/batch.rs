@@ -0,0 +1,201 @@
+use crate::middleware::Pacer;
+use crate::service::{
+    astronomy::{AstroEventRequest, AstroPositionRequest, AstronomyResponse},
+    date_calculator::{
+        BusinessDateRequest, BusinessDateResponse, BusinessDurationRequest,
+        BusinessDurationResponse,
+    },
+    holidays::{HolidaysRequest, HolidaysResponse},
+    onthisday::{OnThisDayRequest, OnThisDayResponse},
+    places::{PlacesRequest, PlacesResponse},
+    tides::{TidesRequest, TidesResponse},
+    time::{
+        ConvertTimeRequest, ConvertTimeResponse, DSTListRequest, DSTListResponse,
+        TimeserviceRequest, TimeserviceResponse,
+    },
+};
+use crate::{ApiError, Error, ServiceClient};
+use maybe_async::maybe_async;
+use std::time::Duration;
+
+#[maybe_async]
+/// Trait implemented by requests which can be executed in a batch.
+pub trait BatchRequest: Sync {
+    /// Response type for the request.
+    type Response: Send;
+
+    /// Execute the request with the given client.
+    async fn execute(
+        &self,
+        client: &ServiceClient,
+    ) -> Result<Result<Self::Response, ApiError>, Error>;
+}
+
+macro_rules! batch_request {
+    ($request:ty, $response:ty, $method:ident) => {
+        #[maybe_async]
+        impl BatchRequest for $request {
+            type Response = $response;
+
+            async fn execute(
+                &self,
+                client: &ServiceClient,
+            ) -> Result<Result<Self::Response, ApiError>, Error> {
+                client.$method(self).await
+            }
+        }
+    };
+}
+
+batch_request!(AstroEventRequest, AstronomyResponse, get_astro_events);
+batch_request!(AstroPositionRequest, AstronomyResponse, get_astro_position);
+batch_request!(
+    BusinessDateRequest,
+    BusinessDateResponse,
+    calculate_business_date
+);
+batch_request!(
+    BusinessDurationRequest,
+    BusinessDurationResponse,
+    calculate_business_duration
+);
+batch_request!(HolidaysRequest, HolidaysResponse, get_holidays);
+batch_request!(OnThisDayRequest, OnThisDayResponse, get_events_on_this_day);
+batch_request!(PlacesRequest, PlacesResponse, get_places);
+batch_request!(TidesRequest, TidesResponse, get_tidal_data);
+batch_request!(ConvertTimeRequest, ConvertTimeResponse, convert_time);
+batch_request!(DSTListRequest, DSTListResponse, get_daylight_savings_time);
+batch_request!(TimeserviceRequest, TimeserviceResponse, get_current_time);
+
+/// Options for executing a batch of requests.
+///
+/// Example:
+/// ```
+/// use libtad_rs::batch::Batch;
+/// use std::time::Duration;
+///
+/// let batch = Batch::new()
+///     .set_concurrency(8)
+///     .set_rate_limit(10, Duration::from_secs(1));
+/// ```
+pub struct Batch {
+    concurrency: usize,
+    interval: Option<Duration>,
+}
+
+impl Default for Batch {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            interval: None,
+        }
+    }
+}
+
+impl Batch {
+    /// Start building batch options. By default, four requests are executed concurrently without
+    /// a rate limit.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the maximum number of requests in flight at the same time.
+    pub fn set_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Limit the batch to at most `requests` requests per `period`. Requests are spaced evenly
+    /// over the period.
+    pub fn set_rate_limit(mut self, requests: u32, period: Duration) -> Self {
+        self.interval = Some(period / requests.max(1));
+        self
+    }
+}
+
+impl ServiceClient {
+    #[cfg(feature = "__sync")]
+    /// Execute a batch of requests on a pool of threads.
+    ///
+    /// Results are returned in the same order as the requests, and a failed request does not
+    /// stop the rest of the batch.
+    pub fn batch<R, I>(
+        &self,
+        requests: I,
+        batch: &Batch,
+    ) -> Vec<Result<Result<R::Response, ApiError>, Error>>
+    where
+        R: BatchRequest,
+        I: IntoIterator<Item = R>,
+    {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Mutex;
+
+        let requests: Vec<R> = requests.into_iter().collect();
+        let pacer = Pacer::new(batch.interval);
+        let position = AtomicUsize::new(0);
+        let results = Mutex::new(requests.iter().map(|_| None).collect::<Vec<_>>());
+
+        std::thread::scope(|scope| {
+            for _ in 0..batch.concurrency.min(requests.len()) {
+                scope.spawn(|| loop {
+                    let i = position.fetch_add(1, Ordering::Relaxed);
+
+                    let request = match requests.get(i) {
+                        Some(request) => request,
+                        None => break,
+                    };
+
+                    if let Some(wait) = pacer.reserve() {
+                        std::thread::sleep(wait);
+                    }
+
+                    let result = request.execute(self);
+                    results.lock().unwrap()[i] = Some(result);
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|result| result.expect("every request is executed"))
+            .collect()
+    }
+
+    #[cfg(feature = "__async")]
+    /// Execute a batch of requests concurrently.
+    ///
+    /// Results are returned in the same order as the requests, and a failed request does not
+    /// stop the rest of the batch.
+    pub async fn batch<R, I>(
+        &self,
+        requests: I,
+        batch: &Batch,
+    ) -> Vec<Result<Result<R::Response, ApiError>, Error>>
+    where
+        R: BatchRequest,
+        I: IntoIterator<Item = R>,
+    {
+        use futures::stream::{self, StreamExt};
+
+        let requests: Vec<R> = requests.into_iter().collect();
+        let pacer = Pacer::new(batch.interval);
+
+        stream::iter(requests.iter().map(|request| {
+            let wait = pacer.reserve();
+
+            async move {
+                if let Some(wait) = wait {
+                    tokio::time::sleep(wait).await;
+                }
+
+                request.execute(self).await
+            }
+        }))
+        .buffered(batch.concurrency)
+        .collect()
+        .await
+    }
+}
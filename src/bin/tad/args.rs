@@ -0,0 +1,475 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(name = "tad", version, about = "Query the Time and Date APIs")]
+pub struct Cli {
+    /// Access key. Read from the config file if not set.
+    #[arg(long, env = "TAD_ACCESS_KEY", global = true, hide_env_values = true)]
+    pub access_key: Option<String>,
+
+    /// Secret key. Read from the config file if not set.
+    #[arg(long, env = "TAD_SECRET_KEY", global = true, hide_env_values = true)]
+    pub secret_key: Option<String>,
+
+    /// JSON or TOML config file with "access_key" and "secret_key". Defaults to
+    /// ~/.config/tad/config.json.
+    #[arg(long, env = "TAD_CONFIG", global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = Format::Table, global = true)]
+    pub format: Format,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// List holidays for one or more countries.
+    Holidays(HolidaysArgs),
+
+    /// Look up places.
+    Places(PlacesArgs),
+
+    /// Time services.
+    #[command(subcommand)]
+    Time(TimeCommand),
+
+    /// Astronomy services.
+    #[command(subcommand)]
+    Astro(AstroCommand),
+
+    /// Predicted tides.
+    Tides(TidesArgs),
+
+    /// Date calculator services.
+    #[command(subcommand)]
+    Business(BusinessCommand),
+
+    /// Events, births and deaths on a given day.
+    Onthisday(OnThisDayArgs),
+}
+
+#[derive(Subcommand)]
+pub enum TimeCommand {
+    /// Convert a time between locations.
+    Convert(ConvertArgs),
+
+    /// Current time in a place.
+    Now(NowArgs),
+
+    /// Daylight savings time for all supported countries.
+    Dst(DstArgs),
+}
+
+#[derive(Subcommand)]
+pub enum AstroCommand {
+    /// Astronomical events, e.g. sunrise and sunset.
+    Events(AstroEventsArgs),
+
+    /// Position of astronomical objects.
+    Position(AstroPositionArgs),
+}
+
+#[derive(Subcommand)]
+pub enum BusinessCommand {
+    /// Find a business date from a number of days.
+    Date(BusinessDateArgs),
+
+    /// Count business days between two dates.
+    Duration(BusinessDurationArgs),
+}
+
+#[derive(Args)]
+pub struct HolidaysArgs {
+    /// Country code. Can be repeated.
+    #[arg(long, required = true)]
+    pub country: Vec<String>,
+
+    /// Year to list holidays for.
+    #[arg(long)]
+    pub year: u16,
+
+    /// Request language. Can be repeated.
+    #[arg(long)]
+    pub lang: Vec<String>,
+
+    /// Holiday type, e.g. federal. Can be repeated.
+    #[arg(long = "type")]
+    pub types: Vec<String>,
+
+    /// Include time zone information.
+    #[arg(long)]
+    pub tz: Option<bool>,
+
+    /// Include verbose time stamps.
+    #[arg(long)]
+    pub verbosetime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct PlacesArgs {
+    /// Place id. Can be repeated.
+    #[arg(long)]
+    pub placeid: Vec<String>,
+
+    /// Search query.
+    #[arg(long)]
+    pub query: Option<String>,
+
+    /// Maximum number of query results.
+    #[arg(long)]
+    pub qlimit: Option<u8>,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Include coordinates.
+    #[arg(long)]
+    pub geo: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct ConvertArgs {
+    /// Place id to convert from.
+    #[arg(long)]
+    pub fromid: String,
+
+    /// Place id to convert to. Can be repeated.
+    #[arg(long)]
+    pub toid: Vec<String>,
+
+    /// Date and time to convert, e.g. 2021-04-05T16:45:02.
+    #[arg(long)]
+    pub datetime: String,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Search radius for coordinates, in kilometers.
+    #[arg(long)]
+    pub radius: Option<i32>,
+
+    /// Include time changes.
+    #[arg(long)]
+    pub timechanges: Option<bool>,
+
+    /// Include time zone information.
+    #[arg(long)]
+    pub tz: Option<bool>,
+
+    /// Include verbose time stamps.
+    #[arg(long)]
+    pub verbosetime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct NowArgs {
+    /// Place id.
+    #[arg(long, required_unless_present = "query")]
+    pub placeid: Option<String>,
+
+    /// Search query.
+    #[arg(long)]
+    pub query: Option<String>,
+
+    /// Maximum number of query results.
+    #[arg(long)]
+    pub qlimit: Option<u8>,
+
+    /// Include coordinates.
+    #[arg(long)]
+    pub geo: Option<bool>,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Search radius for coordinates, in kilometers.
+    #[arg(long)]
+    pub radius: Option<i32>,
+
+    /// Include sunrise and sunset.
+    #[arg(long)]
+    pub sun: Option<bool>,
+
+    /// Include the current time.
+    #[arg(long)]
+    pub time: Option<bool>,
+
+    /// Include time changes.
+    #[arg(long)]
+    pub timechanges: Option<bool>,
+
+    /// Include time zone information.
+    #[arg(long)]
+    pub tz: Option<bool>,
+
+    /// Include verbose time stamps.
+    #[arg(long)]
+    pub verbosetime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct DstArgs {
+    /// Year to list time changes for.
+    #[arg(long)]
+    pub year: Option<i32>,
+
+    /// Country code.
+    #[arg(long)]
+    pub country: Option<String>,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Include places for each country.
+    #[arg(long)]
+    pub listplaces: Option<bool>,
+
+    /// Only include places observing daylight savings time.
+    #[arg(long)]
+    pub onlydst: Option<bool>,
+
+    /// Include time changes.
+    #[arg(long)]
+    pub timechanges: Option<bool>,
+
+    /// Include verbose time stamps.
+    #[arg(long)]
+    pub verbosetime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct AstroEventsArgs {
+    /// Astronomical object, e.g. sun. Can be repeated.
+    #[arg(long, required = true)]
+    pub object: Vec<String>,
+
+    /// Place id. Can be repeated.
+    #[arg(long, required = true)]
+    pub placeid: Vec<String>,
+
+    /// Start date, e.g. 2021-08-18.
+    #[arg(long)]
+    pub startdt: String,
+
+    /// End date.
+    #[arg(long)]
+    pub enddt: Option<String>,
+
+    /// Event class, e.g. setrise. Can be repeated.
+    #[arg(long = "type")]
+    pub types: Vec<String>,
+
+    /// Include coordinates.
+    #[arg(long)]
+    pub geo: Option<bool>,
+
+    /// Include local ISO time stamps.
+    #[arg(long)]
+    pub isotime: Option<bool>,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Search radius for coordinates, in kilometers.
+    #[arg(long)]
+    pub radius: Option<i32>,
+
+    /// Include UTC time stamps.
+    #[arg(long)]
+    pub utctime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct AstroPositionArgs {
+    /// Astronomical object, e.g. moon. Can be repeated.
+    #[arg(long, required = true)]
+    pub object: Vec<String>,
+
+    /// Place id. Can be repeated.
+    #[arg(long, required = true)]
+    pub placeid: Vec<String>,
+
+    /// Point in time, e.g. 2021-08-18T12:00:00. Can be repeated.
+    #[arg(long, required = true)]
+    pub interval: Vec<String>,
+
+    /// Interpret intervals as local time instead of UTC.
+    #[arg(long)]
+    pub localtime: Option<bool>,
+
+    /// Include UTC time stamps.
+    #[arg(long)]
+    pub utctime: Option<bool>,
+
+    /// Include local ISO time stamps.
+    #[arg(long)]
+    pub isotime: Option<bool>,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Search radius for coordinates, in kilometers.
+    #[arg(long)]
+    pub radius: Option<i32>,
+}
+
+#[derive(Args)]
+pub struct TidesArgs {
+    /// Place id. Can be repeated.
+    #[arg(long, required = true)]
+    pub placeid: Vec<String>,
+
+    /// Only return high and low tides.
+    #[arg(long)]
+    pub onlyhighlow: Option<bool>,
+
+    /// Start of the time interval.
+    #[arg(long)]
+    pub startdt: Option<String>,
+
+    /// End of the time interval.
+    #[arg(long)]
+    pub enddt: Option<String>,
+
+    /// Search radius for stations, in kilometers.
+    #[arg(long)]
+    pub radius: Option<i32>,
+
+    /// Resolve subordinate stations.
+    #[arg(long)]
+    pub subordinate: Option<bool>,
+
+    /// Interval between data points, in minutes. Either 5, 15, 30 or 60.
+    #[arg(long)]
+    pub interval: Option<u32>,
+
+    /// Use local time for input and output.
+    #[arg(long)]
+    pub localtime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct BusinessDateArgs {
+    /// Place id.
+    #[arg(long, required_unless_present = "country")]
+    pub placeid: Option<String>,
+
+    /// Country code.
+    #[arg(long)]
+    pub country: Option<String>,
+
+    /// State of the country.
+    #[arg(long)]
+    pub state: Option<String>,
+
+    /// Start date.
+    #[arg(long)]
+    pub startdt: String,
+
+    /// Number of days to count. Can be repeated.
+    #[arg(long, required = true, allow_negative_numbers = true)]
+    pub days: Vec<i32>,
+
+    /// Include instead of exclude the filtered days.
+    #[arg(long)]
+    pub include: Option<bool>,
+
+    /// Filter, e.g. weekendholidays. Can be repeated.
+    #[arg(long)]
+    pub filter: Vec<String>,
+
+    /// Subtract days instead of adding them.
+    #[arg(long)]
+    pub subtract: bool,
+
+    /// Number of times to repeat the calculation.
+    #[arg(long)]
+    pub repeat: Option<i32>,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Include verbose time stamps.
+    #[arg(long)]
+    pub verbosetime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct BusinessDurationArgs {
+    /// Place id.
+    #[arg(long, required_unless_present = "country")]
+    pub placeid: Option<String>,
+
+    /// Country code.
+    #[arg(long)]
+    pub country: Option<String>,
+
+    /// State of the country.
+    #[arg(long)]
+    pub state: Option<String>,
+
+    /// Start date.
+    #[arg(long)]
+    pub startdt: String,
+
+    /// End date.
+    #[arg(long)]
+    pub enddt: String,
+
+    /// Include instead of exclude the filtered days.
+    #[arg(long)]
+    pub include: Option<bool>,
+
+    /// Filter, e.g. weekendholidays. Can be repeated.
+    #[arg(long)]
+    pub filter: Vec<String>,
+
+    /// Count the last date.
+    #[arg(long)]
+    pub includelastdate: Option<bool>,
+
+    /// Request language.
+    #[arg(long)]
+    pub lang: Option<String>,
+
+    /// Include verbose time stamps.
+    #[arg(long)]
+    pub verbosetime: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct OnThisDayArgs {
+    /// Month.
+    #[arg(long)]
+    pub month: Option<u8>,
+
+    /// Day of the month.
+    #[arg(long)]
+    pub day: Option<u8>,
+
+    /// Request language. Can be repeated.
+    #[arg(long)]
+    pub lang: Vec<String>,
+
+    /// Event type, e.g. births. Can be repeated.
+    #[arg(long = "type")]
+    pub types: Vec<String>,
+}
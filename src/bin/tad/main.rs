@@ -0,0 +1,319 @@
+//! Command-line client for the Time and Date APIs.
+
+mod args;
+mod output;
+
+use args::{AstroCommand, BusinessCommand, Cli, Command, TimeCommand};
+use clap::Parser;
+use libtad_rs::{
+    models::{tides::TideInterval, time::DateTime, Distance},
+    service::{
+        astronomy::{AstroEventRequest, AstroPositionRequest},
+        date_calculator::{BusinessDateRequest, BusinessDurationRequest},
+        holidays::HolidaysRequest,
+        onthisday::OnThisDayRequest,
+        places::PlacesRequest,
+        tides::TidesRequest,
+        time::{ConvertTimeRequest, DSTListRequest, TimeserviceRequest},
+    },
+    ApiError, Credentials, Error, ServiceClient,
+};
+use serde::de::DeserializeOwned;
+use std::path::PathBuf;
+
+type Failure = Box<dyn std::error::Error>;
+
+/// Apply an optional builder option.
+macro_rules! option {
+    ($request:ident, $setter:ident, $value:expr) => {
+        if let Some(value) = $value {
+            $request = $request.$setter(value);
+        }
+    };
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(e) = run(cli) {
+        eprintln!("tad: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Failure> {
+    let client = client(&cli)?;
+    let format = cli.format;
+
+    match cli.command {
+        Command::Holidays(args) => {
+            let (first, rest) = args.country.split_first().ok_or("missing --country")?;
+            let mut request = HolidaysRequest::new()
+                .set_year(args.year)
+                .with_country(first);
+
+            for country in rest {
+                request = request.with_country(country);
+            }
+
+            for lang in args.lang {
+                request = request.with_lang(lang);
+            }
+
+            for holiday_type in &args.types {
+                request = request.with_type(parse_enum(holiday_type)?);
+            }
+
+            option!(request, set_tz, args.tz);
+            option!(request, set_verbose_time, args.verbosetime);
+
+            output::print(&response(client.get_holidays(&request))?, format)?;
+        }
+        Command::Places(args) => {
+            let mut request = PlacesRequest::new();
+
+            for placeid in args.placeid {
+                request = request.with_placeid(placeid);
+            }
+
+            option!(request, set_query, args.query);
+            option!(request, set_qlimit, args.qlimit);
+            option!(request, set_lang, args.lang);
+            option!(request, set_geo, args.geo);
+
+            output::print(&response(client.get_places(&request))?, format)?;
+        }
+        Command::Time(TimeCommand::Convert(args)) => {
+            let mut request = ConvertTimeRequest::new()
+                .set_fromid(args.fromid)
+                .set_datetime(DateTime::from(args.datetime));
+
+            for toid in args.toid {
+                request = request.with_toid(toid);
+            }
+
+            option!(request, set_lang, args.lang);
+            option!(request, set_radius, args.radius);
+            option!(request, set_timechanges, args.timechanges);
+            option!(request, set_tz, args.tz);
+            option!(request, set_verbosetime, args.verbosetime);
+
+            output::print(&response(client.convert_time(&request))?, format)?;
+        }
+        Command::Time(TimeCommand::Now(args)) => {
+            let mut request = match (args.placeid, args.query) {
+                (Some(placeid), _) => TimeserviceRequest::new().set_placeid(placeid),
+                (None, Some(query)) => TimeserviceRequest::new().set_query(query),
+                (None, None) => return Err("missing --placeid or --query".into()),
+            };
+
+            option!(request, set_qlimit, args.qlimit);
+            option!(request, set_geo, args.geo);
+            option!(request, set_lang, args.lang);
+            option!(request, set_radius, args.radius);
+            option!(request, set_sun, args.sun);
+            option!(request, set_time, args.time);
+            option!(request, set_timechanges, args.timechanges);
+            option!(request, set_tz, args.tz);
+            option!(request, set_verbosetime, args.verbosetime);
+
+            output::print(&response(client.get_current_time(&request))?, format)?;
+        }
+        Command::Time(TimeCommand::Dst(args)) => {
+            let mut request = DSTListRequest::new();
+
+            option!(request, set_year, args.year);
+            option!(request, set_country, args.country);
+            option!(request, set_lang, args.lang);
+            option!(request, set_listplaces, args.listplaces);
+            option!(request, set_onlydst, args.onlydst);
+            option!(request, set_timechanges, args.timechanges);
+            option!(request, set_verbosetime, args.verbosetime);
+
+            output::print(
+                &response(client.get_daylight_savings_time(&request))?,
+                format,
+            )?;
+        }
+        Command::Astro(AstroCommand::Events(args)) => {
+            let mut request = AstroEventRequest::new()
+                .set_object(parse_enums(&args.object)?)
+                .set_placeid(args.placeid)
+                .set_startdt(DateTime::from(args.startdt));
+
+            for event_type in &args.types {
+                request = request.with_type(parse_enum(event_type)?);
+            }
+
+            option!(request, set_enddt, args.enddt.map(DateTime::from));
+            option!(request, set_geo, args.geo);
+            option!(request, set_isotime, args.isotime);
+            option!(request, set_lang, args.lang);
+            option!(request, set_radius, args.radius);
+            option!(request, set_utctime, args.utctime);
+
+            output::print(&response(client.get_astro_events(&request))?, format)?;
+        }
+        Command::Astro(AstroCommand::Position(args)) => {
+            let mut request = AstroPositionRequest::new()
+                .set_object(parse_enums(&args.object)?)
+                .set_placeid(args.placeid)
+                .set_interval(args.interval.iter().map(DateTime::from).collect());
+
+            option!(request, set_localtime, args.localtime);
+            option!(request, set_utctime, args.utctime);
+            option!(request, set_isotime, args.isotime);
+            option!(request, set_lang, args.lang);
+            option!(request, set_radius, args.radius);
+
+            output::print(&response(client.get_astro_position(&request))?, format)?;
+        }
+        Command::Tides(args) => {
+            let (first, rest) = args.placeid.split_first().ok_or("missing --placeid")?;
+            let mut request = TidesRequest::new().with_placeid(first);
+
+            for placeid in rest {
+                request = request.with_placeid(placeid);
+            }
+
+            option!(request, set_onlyhighlow, args.onlyhighlow);
+            option!(request, set_startdt, args.startdt.map(DateTime::from));
+            option!(request, set_enddt, args.enddt.map(DateTime::from));
+            option!(
+                request,
+                set_radius,
+                args.radius.map(|radius| Distance::from_km(radius as f32))
+            );
+            option!(request, set_subordinate, args.subordinate);
+            option!(
+                request,
+                set_interval,
+                args.interval
+                    .map(|minutes| TideInterval::from_minutes(minutes).ok_or("invalid --interval"))
+                    .transpose()?
+            );
+            option!(request, set_localtime, args.localtime);
+
+            output::print(&response(client.get_tidal_data(&request))?, format)?;
+        }
+        Command::Business(BusinessCommand::Date(args)) => {
+            let request = BusinessDateRequest::new();
+            let mut request = match (args.placeid, args.country) {
+                (Some(placeid), _) => request.set_placeid(placeid),
+                (None, Some(country)) => request.set_country(country),
+                (None, None) => return Err("missing --placeid or --country".into()),
+            }
+            .set_startdt(DateTime::from(args.startdt))
+            .set_days(args.days);
+
+            for filter in &args.filter {
+                request = request.with_filter(parse_enum(filter)?);
+            }
+
+            if args.subtract {
+                request = request.set_subtraction();
+            }
+
+            option!(request, set_state, args.state);
+            option!(request, set_include, args.include);
+            option!(request, set_repeat, args.repeat);
+            option!(request, set_lang, args.lang);
+            option!(request, set_verbosetime, args.verbosetime);
+
+            output::print(&response(client.calculate_business_date(&request))?, format)?;
+        }
+        Command::Business(BusinessCommand::Duration(args)) => {
+            let request = BusinessDurationRequest::new();
+            let mut request = match (args.placeid, args.country) {
+                (Some(placeid), _) => request.set_placeid(placeid),
+                (None, Some(country)) => request.set_country(country),
+                (None, None) => return Err("missing --placeid or --country".into()),
+            }
+            .set_startdt(DateTime::from(args.startdt))
+            .set_enddt(DateTime::from(args.enddt));
+
+            for filter in &args.filter {
+                request = request.with_filter(parse_enum(filter)?);
+            }
+
+            option!(request, set_state, args.state);
+            option!(request, set_include, args.include);
+            option!(request, set_includelastdate, args.includelastdate);
+            option!(request, set_lang, args.lang);
+            option!(request, set_verbosetime, args.verbosetime);
+
+            output::print(
+                &response(client.calculate_business_duration(&request))?,
+                format,
+            )?;
+        }
+        Command::Onthisday(args) => {
+            let mut request = OnThisDayRequest::new();
+
+            option!(request, set_month, args.month);
+            option!(request, set_day, args.day);
+
+            for lang in args.lang {
+                request = request.with_lang(lang);
+            }
+
+            for event_type in &args.types {
+                request = request.with_type(parse_enum(event_type)?);
+            }
+
+            output::print(&response(client.get_events_on_this_day(&request))?, format)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Build a client from command line arguments, environment variables or the config file. Keys
+/// given on the command line or in the environment override the keys of the config file.
+fn client(cli: &Cli) -> Result<ServiceClient, Failure> {
+    if let (Some(access_key), Some(secret_key)) = (&cli.access_key, &cli.secret_key) {
+        let credentials = Credentials::new(access_key.as_str(), secret_key.as_str());
+        return Ok(ServiceClient::with_credentials(credentials));
+    }
+
+    let path = cli
+        .config
+        .clone()
+        .or_else(default_config)
+        .ok_or("missing credentials, set TAD_ACCESS_KEY and TAD_SECRET_KEY")?;
+
+    let mut credentials = Credentials::from_file(&path)
+        .map_err(|e| format!("could not load {}: {}", path.display(), e))?;
+
+    if let Some(ref access_key) = cli.access_key {
+        credentials = credentials.set_access_key(access_key.as_str());
+    }
+
+    if let Some(ref secret_key) = cli.secret_key {
+        credentials = credentials.set_secret_key(secret_key.as_str());
+    }
+
+    Ok(ServiceClient::with_credentials(credentials))
+}
+
+fn default_config() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(base.join("tad").join("config.json"))
+}
+
+fn response<T>(result: Result<Result<T, ApiError>, Error>) -> Result<T, Failure> {
+    Ok(result??)
+}
+
+/// Parse an enum value using its API name, e.g. "weekendholidays".
+fn parse_enum<T: DeserializeOwned>(value: &str) -> Result<T, Failure> {
+    serde_json::from_value(serde_json::Value::String(value.to_lowercase()))
+        .map_err(|_| format!("invalid value: {}", value).into())
+}
+
+fn parse_enums<T: DeserializeOwned>(values: &[String]) -> Result<Vec<T>, Failure> {
+    values.iter().map(|v| parse_enum(v)).collect()
+}
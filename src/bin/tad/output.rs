@@ -0,0 +1,75 @@
+use crate::args::Format;
+use libtad_rs::tabular::Tabular;
+use serde_json::Value;
+use std::io::{self, Write};
+
+/// Print a response in the requested format.
+pub fn print<T: Tabular>(response: &T, format: Format) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    match format {
+        Format::Csv => response.write_csv(&mut out),
+        Format::Json => {
+            let rows: Vec<_> = response
+                .rows()
+                .into_iter()
+                .map(|row| {
+                    let object = T::COLUMNS
+                        .iter()
+                        .map(|c| c.to_string())
+                        .zip(row)
+                        .collect::<serde_json::Map<_, _>>();
+
+                    Value::Object(object)
+                })
+                .collect();
+
+            serde_json::to_writer_pretty(&mut out, &rows)?;
+            writeln!(out)
+        }
+        Format::Table => write_table(&mut out, T::COLUMNS, response.rows()),
+    }
+}
+
+fn write_table<W: Write>(out: &mut W, columns: &[&str], rows: Vec<Vec<Value>>) -> io::Result<()> {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(cell).collect())
+        .collect();
+
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(column.len()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let header: Vec<String> = columns.iter().map(|c| c.to_uppercase()).collect();
+
+    for row in std::iter::once(&header).chain(cells.iter()) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(value, width)| format!("{:<width$}", value, width = width))
+            .collect();
+
+        writeln!(out, "{}", line.join("  ").trim_end())?;
+    }
+
+    Ok(())
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.replace('\n', " "),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+
+/// Source of the current time used to sign requests.
+///
+/// It is implemented for closures returning the current time, which makes it easy to sign
+/// requests at a fixed point in time in tests.
+///
+/// Example:
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use libtad_rs::ServiceClient;
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into())
+///     .set_clock(|| Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap());
+/// ```
+pub trait Clock: Send + Sync {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock reading the time of the system.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> DateTime<Utc> + Send + Sync,
+{
+    fn now(&self) -> DateTime<Utc> {
+        self()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::models::time::DateTime;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+
+/// Date component of a [`DateTime`], if it describes a valid calendar date.
+pub(crate) fn naive_date(datetime: &DateTime) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(datetime.year, datetime.month as u32, datetime.day as u32)
+}
+
+/// Date-only [`DateTime`] for a calendar date.
+pub(crate) fn from_naive_date(date: NaiveDate) -> DateTime {
+    DateTime {
+        year: date.year(),
+        month: date.month() as i32,
+        day: date.day() as i32,
+        ..Default::default()
+    }
+}
+
+/// Date and time of a [`DateTime`], if it describes a valid calendar date and time of day.
+pub(crate) fn naive_datetime(datetime: &DateTime) -> Option<NaiveDateTime> {
+    naive_date(datetime)?.and_hms_opt(
+        datetime.hour as u32,
+        datetime.minute as u32,
+        datetime.second as u32,
+    )
+}
+
+/// [`DateTime`] for a calendar date and time of day.
+pub(crate) fn from_naive_datetime(datetime: NaiveDateTime) -> DateTime {
+    DateTime {
+        hour: datetime.hour() as i32,
+        minute: datetime.minute() as i32,
+        second: datetime.second() as i32,
+        ..from_naive_date(datetime.date())
+    }
+}
+
+/// Split the range between two timestamps into consecutive ranges of at most `span`. Each range
+/// starts where the previous one ended. Ranges which cannot be split are returned as-is.
+pub(crate) fn split_range(
+    start: &DateTime,
+    end: &DateTime,
+    span: Duration,
+) -> Vec<(DateTime, DateTime)> {
+    let (mut from, end) = match (naive_datetime(start), naive_datetime(end)) {
+        (Some(start), Some(end)) if start < end && span > Duration::zero() => (start, end),
+        _ => return vec![(*start, *end)],
+    };
+
+    let mut ranges = Vec::new();
+
+    while from < end {
+        let to = (from + span).min(end);
+        ranges.push((from_naive_datetime(from), from_naive_datetime(to)));
+        from = to;
+    }
+
+    ranges
+}
+
+/// Widen a single precision value without the noise of its binary representation.
+pub(crate) fn widen(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or_default()
+}
+
+#[test]
+fn split_range_into_spans() {
+    let ranges = split_range(
+        &DateTime::from("2021-01-01"),
+        &DateTime::from("2021-01-20"),
+        Duration::days(7),
+    );
+
+    assert!(ranges.len() == 3);
+    assert!(ranges[0].0 == DateTime::from("2021-01-01"));
+    assert!(ranges[0].1 == DateTime::from("2021-01-08"));
+    assert!(ranges[1].0 == ranges[0].1);
+    assert!(ranges[2].1 == DateTime::from("2021-01-20"));
+}
+
+#[test]
+fn split_range_keeps_short_and_invalid_ranges() {
+    let start = DateTime::from("2021-01-01T06:00:00");
+    let end = DateTime::from("2021-01-01T18:00:00");
+
+    assert!(split_range(&start, &end, Duration::days(7)) == vec![(start, end)]);
+    assert!(split_range(&end, &start, Duration::days(7)) == vec![(end, start)]);
+}
@@ -0,0 +1,220 @@
+use serde::Deserialize;
+use std::path::Path;
+use zeroize::{Zeroize, Zeroizing};
+
+#[derive(Clone, Deserialize)]
+/// Access and secret key used to authenticate requests.
+///
+/// The keys are wiped from memory when dropped, and the secret key is redacted when the
+/// credentials are printed with `{:?}`.
+///
+/// Example:
+/// ```
+/// use libtad_rs::Credentials;
+///
+/// let credentials = Credentials::new("access", "secret");
+///
+/// assert!(credentials.access_key() == "access");
+/// assert!(!format!("{:?}", credentials).contains("\"secret\""));
+/// ```
+pub struct Credentials {
+    access_key: String,
+    secret_key: String,
+}
+
+impl Credentials {
+    /// Environment variable read by [`Credentials::from_env`] for the access key.
+    pub const ACCESS_KEY_VAR: &'static str = "TAD_ACCESS_KEY";
+
+    /// Environment variable read by [`Credentials::from_env`] for the secret key.
+    pub const SECRET_KEY_VAR: &'static str = "TAD_SECRET_KEY";
+
+    /// Create credentials from an access and secret key.
+    pub fn new(access_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+
+    /// Read credentials from the `TAD_ACCESS_KEY` and `TAD_SECRET_KEY` environment variables.
+    pub fn from_env() -> Result<Self, CredentialsError> {
+        Self::from_env_vars(Self::ACCESS_KEY_VAR, Self::SECRET_KEY_VAR)
+    }
+
+    /// Read credentials from the given environment variables.
+    pub fn from_env_vars(access_key: &str, secret_key: &str) -> Result<Self, CredentialsError> {
+        let var = |name: &str| {
+            std::env::var(name).map_err(|_| CredentialsError::MissingVariable(name.into()))
+        };
+
+        Ok(Self::new(var(access_key)?, var(secret_key)?))
+    }
+
+    /// Read credentials from a config file with an `access_key` and a `secret_key`.
+    ///
+    /// Files ending in `.toml` are read as TOML if the "toml" feature is enabled. All other files
+    /// are read as JSON.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CredentialsError> {
+        let path = path.as_ref();
+        let contents = Zeroizing::new(std::fs::read_to_string(path)?);
+
+        #[cfg(feature = "toml")]
+        if path.extension() == Some("toml".as_ref()) {
+            return Self::from_toml(&contents);
+        }
+
+        Self::from_json(&contents)
+    }
+
+    /// Parse credentials from a JSON object with an `access_key` and a `secret_key`.
+    ///
+    /// Errors only report where parsing failed, so the keys never end up in error messages.
+    pub fn from_json(json: &str) -> Result<Self, CredentialsError> {
+        serde_json::from_str(json).map_err(|e| {
+            let kind = match e.classify() {
+                serde_json::error::Category::Data => "missing or invalid keys",
+                _ => "invalid JSON",
+            };
+
+            CredentialsError::Parse(format!(
+                "{} at line {}, column {}",
+                kind,
+                e.line(),
+                e.column()
+            ))
+        })
+    }
+
+    #[cfg(feature = "toml")]
+    /// Parse credentials from a TOML document with an `access_key` and a `secret_key`.
+    ///
+    /// Errors only report where parsing failed, so the keys never end up in error messages.
+    pub fn from_toml(toml: &str) -> Result<Self, CredentialsError> {
+        toml::from_str(toml).map_err(|e| {
+            let message = match e.span().and_then(|span| toml.get(..span.start)) {
+                Some(before) => format!("invalid TOML at line {}", before.lines().count().max(1)),
+                None => "invalid TOML".into(),
+            };
+
+            CredentialsError::Parse(message)
+        })
+    }
+
+    /// Replace the access key, e.g. to override a key loaded from a file.
+    pub fn set_access_key(mut self, access_key: impl Into<String>) -> Self {
+        self.access_key.zeroize();
+        self.access_key = access_key.into();
+
+        self
+    }
+
+    /// Replace the secret key, e.g. to override a key loaded from a file.
+    pub fn set_secret_key(mut self, secret_key: impl Into<String>) -> Self {
+        self.secret_key.zeroize();
+        self.secret_key = secret_key.into();
+
+        self
+    }
+
+    /// Fetch credentials from a provider, e.g. a secret store.
+    pub fn from_provider(provider: &impl CredentialsProvider) -> Result<Self, CredentialsError> {
+        provider.credentials()
+    }
+
+    /// The access key.
+    pub fn access_key(&self) -> &str {
+        &self.access_key
+    }
+
+    pub(crate) fn secret_key(&self) -> &str {
+        &self.secret_key
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Drop for Credentials {
+    fn drop(&mut self) {
+        self.access_key.zeroize();
+        self.secret_key.zeroize();
+    }
+}
+
+/// Trait implemented by sources of credentials, e.g. a secret store.
+///
+/// It is implemented for closures returning credentials.
+///
+/// Example:
+/// ```
+/// use libtad_rs::{Credentials, CredentialsError};
+///
+/// let provider = || -> Result<Credentials, CredentialsError> {
+///     Ok(Credentials::new("access_key", "secret_key"))
+/// };
+///
+/// let credentials = Credentials::from_provider(&provider).unwrap();
+/// ```
+pub trait CredentialsProvider {
+    /// Fetch the credentials.
+    fn credentials(&self) -> Result<Credentials, CredentialsError>;
+}
+
+impl<F> CredentialsProvider for F
+where
+    F: Fn() -> Result<Credentials, CredentialsError>,
+{
+    fn credentials(&self) -> Result<Credentials, CredentialsError> {
+        self()
+    }
+}
+
+#[derive(Debug)]
+/// Error when loading credentials.
+pub enum CredentialsError {
+    /// Environment variable is not set or not valid unicode.
+    MissingVariable(String),
+
+    /// Config file could not be read.
+    Io(std::io::Error),
+
+    /// Config file could not be parsed.
+    Parse(String),
+
+    /// Error returned from a credentials provider.
+    Provider(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl std::fmt::Display for CredentialsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MissingVariable(name) => write!(f, "Missing environment variable: {}", name),
+            Self::Io(e) => write!(f, "IO Error: {}", e),
+            Self::Parse(e) => write!(f, "Parse Error: {}", e),
+            Self::Provider(e) => write!(f, "Provider Error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CredentialsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Provider(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CredentialsError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
@@ -10,6 +10,12 @@ pub enum Error {
 
     /// Error when generating signature.
     Signature(InvalidKeyLength),
+
+    /// Error when deserializing a response.
+    Deserialize(serde_json::Error),
+
+    /// Request was rejected before it was sent.
+    Validation(ValidationError),
 }
 
 impl std::fmt::Display for Error {
@@ -17,6 +23,8 @@ impl std::fmt::Display for Error {
         match self {
             Self::Http(e) => write!(f, "HTTP Error: {}", e),
             Self::Signature(e) => write!(f, "Signature Error: {}", e),
+            Self::Deserialize(e) => write!(f, "Deserialize Error: {}", e),
+            Self::Validation(e) => write!(f, "Validation Error: {}", e),
         }
     }
 }
@@ -25,6 +33,8 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Http(e) => Some(e),
+            Self::Deserialize(e) => Some(e),
+            Self::Validation(e) => Some(e),
             _ => None,
         }
     }
@@ -36,12 +46,51 @@ impl From<HTTPError> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Deserialize(e)
+    }
+}
+
 impl From<InvalidKeyLength> for Error {
     fn from(e: InvalidKeyLength) -> Self {
         Self::Signature(e)
     }
 }
 
+impl From<ValidationError> for Error {
+    fn from(e: ValidationError) -> Self {
+        Self::Validation(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Invalid combination of request arguments.
+pub struct ValidationError {
+    /// Name of the offending request argument.
+    pub argument: &'static str,
+
+    /// Description of the problem.
+    pub message: String,
+}
+
+impl ValidationError {
+    pub(crate) fn new(argument: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            argument,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.argument, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
 #[derive(Debug, Deserialize)]
 /// Error returned from the API.
 pub struct ApiError {
@@ -0,0 +1,119 @@
+use crate::convert::widen;
+use crate::models::places::{Geo, Location};
+use crate::service::{places::PlacesResponse, tides::TidesResponse, time::TimeserviceResponse};
+use ::geojson::{feature::Id, Feature, FeatureCollection, Geometry, JsonObject, Value};
+
+/// Trait implemented by responses which can be exported as GeoJSON.
+///
+/// Every place, station or location becomes a feature with a point geometry, or without a
+/// geometry if the coordinates were not returned.
+///
+/// Example:
+/// ```
+/// use libtad_rs::{geojson::ToGeoJson, service::places::PlacesResponse};
+///
+/// let response: PlacesResponse = serde_json::from_str(r#"{ "places": [] }"#).unwrap();
+/// let collection = response.to_geojson();
+///
+/// assert!(collection.features.is_empty());
+/// ```
+pub trait ToGeoJson {
+    /// Convert the response to a GeoJSON feature collection.
+    fn to_geojson(&self) -> FeatureCollection;
+}
+
+impl ToGeoJson for PlacesResponse {
+    fn to_geojson(&self) -> FeatureCollection {
+        collection(self.places.iter().map(|place| {
+            let mut properties = geo_properties(&place.geo);
+            properties.insert("urlid".into(), place.urlid.as_str().into());
+
+            feature(
+                Some(Id::Number(place.id.into())),
+                point(place.geo.latitude, place.geo.longitude),
+                properties,
+            )
+        }))
+    }
+}
+
+impl ToGeoJson for TidesResponse {
+    fn to_geojson(&self) -> FeatureCollection {
+        collection(self.stations.iter().map(|station| {
+            let source = &station.source;
+            let mut properties = JsonObject::new();
+
+            properties.insert("name".into(), source.name.as_str().into());
+            properties.insert("type".into(), source.r#type.as_str().into());
+            properties.insert("distance".into(), widen(source.distance.km()).into());
+            properties.insert("matchparam".into(), station.matchparam.as_str().into());
+
+            feature(
+                None,
+                point(Some(source.latitude), Some(source.longitude)),
+                properties,
+            )
+        }))
+    }
+}
+
+impl ToGeoJson for TimeserviceResponse {
+    fn to_geojson(&self) -> FeatureCollection {
+        collection(self.locations.iter().map(location_feature))
+    }
+}
+
+fn location_feature(location: &Location) -> Feature {
+    let mut properties = geo_properties(&location.geo);
+    properties.insert("matchparam".into(), location.matchparam.as_str().into());
+
+    if let Some(ref time) = location.time {
+        properties.insert("time".into(), time.iso.as_str().into());
+
+        if let Some(ref timezone) = time.timezone {
+            properties.insert("zoneabb".into(), timezone.zoneabb.as_str().into());
+            properties.insert("offset".into(), timezone.offset.as_str().into());
+        }
+    }
+
+    feature(
+        Some(Id::String(location.id.clone())),
+        point(location.geo.latitude, location.geo.longitude),
+        properties,
+    )
+}
+
+fn geo_properties(geo: &Geo) -> JsonObject {
+    let mut properties = JsonObject::new();
+
+    properties.insert("name".into(), geo.name.as_str().into());
+    properties.insert("state".into(), geo.state.as_deref().into());
+    properties.insert("country_id".into(), geo.country.id.as_str().into());
+    properties.insert("country_name".into(), geo.country.name.as_str().into());
+
+    properties
+}
+
+fn point(latitude: Option<f32>, longitude: Option<f32>) -> Option<Geometry> {
+    let position = vec![widen(longitude?), widen(latitude?)];
+
+    Some(Geometry::new(Value::Point(position)))
+}
+
+fn feature(id: Option<Id>, geometry: Option<Geometry>, properties: JsonObject) -> Feature {
+    Feature {
+        bbox: None,
+        geometry,
+        id,
+        properties: Some(properties),
+        foreign_members: None,
+    }
+}
+
+fn collection(features: impl Iterator<Item = Feature>) -> FeatureCollection {
+    FeatureCollection {
+        bbox: None,
+        features: features.collect(),
+        foreign_members: None,
+    }
+}
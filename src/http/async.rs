@@ -2,8 +2,8 @@ use maybe_async::async_impl;
 use reqwest::Client;
 use url::Url;
 
-use super::{ApiResponse, HTTPBaseClient};
-use crate::{service::Service, ApiError, Error};
+use super::{server_date, HTTPBaseClient};
+use crate::{middleware::Response, Error};
 
 pub type HTTPError = reqwest::Error;
 
@@ -26,14 +26,17 @@ impl AsyncClient {
 
 #[async_impl]
 impl HTTPBaseClient for AsyncClient {
-    async fn get<S: Service>(&self, url: Url) -> Result<Result<S::Response, ApiError>, Error> {
-        Ok(self
-            .client
-            .get(url)
-            .send()
-            .await?
-            .json::<ApiResponse<S>>()
-            .await?
-            .into())
+    async fn get(&self, url: Url) -> Result<Response, Error> {
+        let response = self.client.get(url).send().await?;
+
+        let date = server_date(
+            response
+                .headers()
+                .get(reqwest::header::DATE)
+                .and_then(|d| d.to_str().ok()),
+        );
+        let body = response.bytes().await?;
+
+        Ok(Response::new(body.to_vec(), date))
     }
 }
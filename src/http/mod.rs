@@ -1,11 +1,26 @@
-use crate::{service::Service, ApiError, Error};
+use crate::{middleware::Response, service::Service, ApiError, Error};
+use chrono::{DateTime, Utc};
 use maybe_async::maybe_async;
 use serde::Deserialize;
 use url::Url;
 
 #[maybe_async]
 pub trait HTTPBaseClient {
-    async fn get<S: Service>(&self, url: Url) -> Result<Result<S::Response, ApiError>, Error>;
+    async fn get(&self, url: Url) -> Result<Response, Error>;
+}
+
+/// Parse a response body.
+pub fn parse<S: Service>(body: &[u8]) -> Result<Result<S::Response, ApiError>, Error> {
+    let response: ApiResponse<S> = serde_json::from_slice(body)?;
+
+    Ok(response.into())
+}
+
+/// Parse the value of a `Date` header, e.g. "Sun, 04 Apr 2021 16:42:21 GMT".
+pub fn server_date(value: Option<&str>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(value?)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
 }
 
 #[derive(Deserialize)]
@@ -1,8 +1,8 @@
-use attohttpc::{header, Method, RequestBuilder, Response};
+use attohttpc::{header, Method, RequestBuilder};
 use url::Url;
 
-use super::{ApiResponse, HTTPBaseClient};
-use crate::{service::Service, ApiError, Error};
+use super::{server_date, HTTPBaseClient};
+use crate::{middleware::Response, Error};
 
 pub type HTTPError = attohttpc::Error;
 
@@ -17,11 +17,19 @@ impl SyncClient {
 }
 
 impl HTTPBaseClient for SyncClient {
-    fn get<S: Service>(&self, url: Url) -> Result<Result<S::Response, ApiError>, Error> {
-        Ok(RequestBuilder::try_new(Method::GET, url)
+    fn get(&self, url: Url) -> Result<Response, Error> {
+        let response = RequestBuilder::try_new(Method::GET, url)
             .and_then(|r| RequestBuilder::try_header(r, header::USER_AGENT, Self::USER_AGENT))
-            .and_then(RequestBuilder::send)
-            .and_then(Response::json::<ApiResponse<S>>)?
-            .into())
+            .and_then(RequestBuilder::send)?;
+
+        let date = server_date(
+            response
+                .headers()
+                .get(header::DATE)
+                .and_then(|d| d.to_str().ok()),
+        );
+        let body = response.bytes()?;
+
+        Ok(Response::new(body, date))
     }
 }
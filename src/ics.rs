@@ -0,0 +1,606 @@
+use crate::convert::{naive_date, naive_datetime};
+use crate::models::{
+    astronomy::{AstronomyDay, AstronomyDayEvent, AstronomyObjectType},
+    holidays::Holiday,
+    places::Country,
+    time::{DateTime, Time},
+    Text,
+};
+use crate::service::{astronomy::AstronomyResponse, holidays::HolidaysResponse};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+use std::collections::BTreeMap;
+
+const PRODID: &str = concat!(
+    "-//Time and Date//libtad-rs ",
+    env!("CARGO_PKG_VERSION"),
+    "//EN"
+);
+
+#[derive(Default)]
+/// An iCalendar (RFC 5545) calendar built from holidays and astronomical events.
+///
+/// Holidays without a time are rendered as all-day events. Holidays with an exact time are
+/// rendered as timed events in UTC when the offset is known, and as floating local time
+/// otherwise. Astronomical events are rendered in UTC, and require the `utctime` or `isotime`
+/// parameter of the request; events with neither are skipped.
+///
+/// Example:
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use libtad_rs::ics::ICalendar;
+/// use libtad_rs::service::holidays::HolidaysResponse;
+///
+/// let response: HolidaysResponse = serde_json::from_str(r#"{ "holidays": [] }"#).unwrap();
+/// let calendar = ICalendar::new()
+///     .set_name("Holidays")
+///     .set_timestamp(Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap())
+///     .with_holidays(&response);
+///
+/// let ics = calendar.to_string();
+/// let holidays = libtad_rs::ics::parse_holidays(&ics).unwrap();
+/// ```
+pub struct ICalendar {
+    name: Option<String>,
+    lang: Option<String>,
+    timestamp: NaiveDateTime,
+    events: Vec<Event>,
+}
+
+#[derive(Default)]
+struct Event {
+    properties: Vec<(String, String)>,
+}
+
+impl Event {
+    fn push(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.properties.push((name.into(), value.into()));
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(n, _)| property_name(n).eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn param(&self, name: &str, param: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(n, _)| property_name(n).eq_ignore_ascii_case(name))
+            .and_then(|(n, _)| {
+                n.split(';').skip(1).find_map(|p| {
+                    p.split_once('=')
+                        .filter(|(k, _)| k.eq_ignore_ascii_case(param))
+                        .map(|(_, v)| v)
+                })
+            })
+    }
+}
+
+impl ICalendar {
+    /// Start building a new calendar.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the display name of the calendar.
+    pub fn set_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Set the preferred language for names and descriptions. Defaults to the first returned
+    /// language.
+    pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+
+        self
+    }
+
+    /// Set the time the calendar is created, written as the DTSTAMP of every event. Defaults to
+    /// the Unix epoch, so the same responses always render the same calendar.
+    pub fn set_timestamp(mut self, timestamp: chrono::DateTime<Utc>) -> Self {
+        self.timestamp = timestamp.naive_utc();
+
+        self
+    }
+
+    /// Add all holidays of a response as events.
+    pub fn with_holidays(mut self, response: &HolidaysResponse) -> Self {
+        for holiday in &response.holidays {
+            let event = self.holiday_event(holiday);
+            self.events.push(event);
+        }
+
+        self
+    }
+
+    /// Add all astronomical day events of a response as events. Events without a UTC time or a
+    /// local time with offset are skipped.
+    pub fn with_astronomy(mut self, response: &AstronomyResponse) -> Self {
+        for location in &response.locations {
+            for object in &location.astronomy.objects {
+                for day in object.days.iter().flatten() {
+                    for day_event in &day.events {
+                        let utc = match utc_time(day_event) {
+                            Some(utc) => utc,
+                            None => continue,
+                        };
+
+                        let mut event = Event::default();
+                        let (object_id, object_name) = object_name(&object.name);
+                        let localtime = format!(
+                            "{:02}{:02}{:02}",
+                            day_event.hour, day_event.min, day_event.sec
+                        );
+
+                        event.push(
+                            "UID",
+                            format!(
+                                "{}-{}-{}T{}-{}@timeanddate.com",
+                                location.id,
+                                object_id,
+                                date_value(&day.date),
+                                localtime,
+                                day_event.r#type
+                            ),
+                        );
+                        event.push("DTSTART", utc_value(&utc));
+                        event.push(
+                            "SUMMARY",
+                            escape(&format!("{} {}", object_name, day_event.r#type)),
+                        );
+                        event.push("LOCATION", escape(&location.geo.name));
+                        event.push("X-TAD-LOCATION", escape(&location.id));
+                        event.push("X-TAD-OBJECT", object_id);
+                        event.push("X-TAD-EVENT", escape(day_event.r#type.as_str()));
+                        event.push("X-TAD-DATE", date_value(&day.date));
+                        event.push("X-TAD-LOCALTIME", localtime);
+
+                        self.events.push(event);
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
+    fn holiday_event(&self, holiday: &Holiday) -> Event {
+        let mut event = Event::default();
+        let datetime = &holiday.date.datetime;
+
+        event.push("UID", format!("{}@timeanddate.com", holiday.uid));
+
+        if is_date_only(&holiday.date) {
+            event.push("DTSTART;VALUE=DATE", date_value(datetime));
+
+            if let Some(end) = naive_date(datetime).and_then(|d| d.succ_opt()) {
+                event.push("DTEND;VALUE=DATE", end.format("%Y%m%d").to_string());
+            }
+        } else {
+            let offset = holiday.date.timezone.as_ref().map(|t| t.zonetotaloffset);
+
+            match (naive_datetime(datetime), offset) {
+                (Some(local), Some(offset)) => event.push(
+                    "DTSTART",
+                    utc_value(&(local - Duration::seconds(offset.into()))),
+                ),
+                _ => event.push("DTSTART", local_value(datetime)),
+            }
+        }
+
+        if let Some(name) = self.localized(&holiday.name) {
+            event.push(
+                format!("SUMMARY;LANGUAGE={}", name.lang),
+                escape(&name.text),
+            );
+        }
+
+        if let Some(oneliner) = holiday.oneliner.as_ref().and_then(|o| self.localized(o)) {
+            event.push(
+                format!("DESCRIPTION;LANGUAGE={}", oneliner.lang),
+                escape(&oneliner.text),
+            );
+        }
+
+        event.push("URL", holiday.url.as_str());
+
+        if let Some(ref types) = holiday.types {
+            event.push(
+                "CATEGORIES",
+                types
+                    .iter()
+                    .map(|t| escape(t))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+        }
+
+        if let Some(ref country) = holiday.country {
+            event.push("X-TAD-COUNTRY", escape(&country.id));
+            event.push("X-TAD-COUNTRY-NAME", escape(&country.name));
+        }
+
+        if let Some(ref locations) = holiday.locations {
+            event.push("LOCATION", escape(locations));
+        }
+
+        event.push("X-TAD-HOLIDAY-ID", holiday.id.to_string());
+        event.push("X-TAD-DATE", escape(&holiday.date.iso));
+
+        event
+    }
+
+    fn localized<'a>(&self, texts: &'a [Text]) -> Option<&'a Text> {
+        self.lang
+            .as_ref()
+            .and_then(|lang| texts.iter().find(|t| t.lang.eq_ignore_ascii_case(lang)))
+            .or_else(|| texts.first())
+    }
+}
+
+impl std::fmt::Display for ICalendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write_line(f, "BEGIN:VCALENDAR")?;
+        write_line(f, "VERSION:2.0")?;
+        write_line(f, &format!("PRODID:{}", PRODID))?;
+        write_line(f, "CALSCALE:GREGORIAN")?;
+
+        if let Some(ref name) = self.name {
+            write_line(f, &format!("X-WR-CALNAME:{}", escape(name)))?;
+        }
+
+        for event in &self.events {
+            write_line(f, "BEGIN:VEVENT")?;
+            write_line(f, &format!("DTSTAMP:{}", utc_value(&self.timestamp)))?;
+
+            for (name, value) in &event.properties {
+                write_line(f, &format!("{}:{}", name, value))?;
+            }
+
+            write_line(f, "END:VEVENT")?;
+        }
+
+        write_line(f, "END:VCALENDAR")
+    }
+}
+
+#[derive(Debug)]
+/// Error returned when parsing an iCalendar document.
+pub struct ParseError {
+    /// Line number of the unfolded line which could not be parsed.
+    pub line: usize,
+
+    /// Description of the error.
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "iCalendar Error on line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse holidays from an iCalendar document produced by [`ICalendar`].
+///
+/// Events without holiday information are skipped.
+pub fn parse_holidays(input: &str) -> Result<Vec<Holiday>, ParseError> {
+    let mut holidays = Vec::new();
+
+    for (line, event) in parse_events(input)? {
+        let id = match event.get("X-TAD-HOLIDAY-ID") {
+            Some(id) => id.parse().map_err(|_| ParseError {
+                line,
+                message: format!("invalid holiday id: {}", id),
+            })?,
+            None => continue,
+        };
+
+        let uid = event.get("UID").ok_or_else(|| missing(line, "UID"))?;
+        let url = event.get("URL").ok_or_else(|| missing(line, "URL"))?;
+        let iso = unescape(
+            event
+                .get("X-TAD-DATE")
+                .ok_or_else(|| missing(line, "X-TAD-DATE"))?,
+        );
+
+        let text = |name: &str| {
+            event.get(name).map(|value| Text {
+                lang: event.param(name, "LANGUAGE").unwrap_or("en").into(),
+                text: unescape(value),
+            })
+        };
+
+        holidays.push(Holiday {
+            id,
+            uid: uid.trim_end_matches("@timeanddate.com").into(),
+            name: text("SUMMARY").into_iter().collect(),
+            date: Time {
+                datetime: DateTime::from(iso.get(..19).unwrap_or(&iso)),
+                iso,
+                timezone: None,
+            },
+            url: url.parse().map_err(|e| ParseError {
+                line,
+                message: format!("invalid url: {}", e),
+            })?,
+            country: event.get("X-TAD-COUNTRY").map(|id| Country {
+                id: unescape(id),
+                name: event
+                    .get("X-TAD-COUNTRY-NAME")
+                    .map(unescape)
+                    .unwrap_or_default(),
+            }),
+            locations: event.get("LOCATION").map(unescape),
+            states: None,
+            oneliner: text("DESCRIPTION").map(|t| vec![t]),
+            types: event
+                .get("CATEGORIES")
+                .map(|c| split_list(c).iter().map(|t| unescape(t)).collect()),
+        });
+    }
+
+    Ok(holidays)
+}
+
+/// Parse astronomical days from an iCalendar document produced by [`ICalendar`].
+///
+/// Events are grouped by date, and events without astronomical information are skipped.
+pub fn parse_astronomy_days(input: &str) -> Result<Vec<AstronomyDay>, ParseError> {
+    let mut days: BTreeMap<NaiveDate, Vec<AstronomyDayEvent>> = BTreeMap::new();
+
+    for (line, event) in parse_events(input)? {
+        let r#type = match event.get("X-TAD-EVENT") {
+            Some(t) => unescape(t).into(),
+            None => continue,
+        };
+
+        let date = event
+            .get("X-TAD-DATE")
+            .ok_or_else(|| missing(line, "X-TAD-DATE"))?;
+        let date = Some(date)
+            .filter(|d| d.len() == 8 && d.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok())
+            .ok_or_else(|| ParseError {
+                line,
+                message: format!("invalid date: {}", date),
+            })?;
+        let localtime = event
+            .get("X-TAD-LOCALTIME")
+            .ok_or_else(|| missing(line, "X-TAD-LOCALTIME"))?;
+
+        let component = |range: std::ops::Range<usize>| {
+            localtime
+                .get(range)
+                .and_then(|c| c.parse().ok())
+                .ok_or_else(|| ParseError {
+                    line,
+                    message: format!("invalid local time: {}", localtime),
+                })
+        };
+
+        let utctime = event
+            .get("DTSTART")
+            .filter(|v| v.ends_with('Z'))
+            .and_then(|v| NaiveDateTime::parse_from_str(v, "%Y%m%dT%H%M%SZ").ok())
+            .map(|utc| DateTime::from(utc.format("%Y-%m-%dT%H:%M:%S").to_string()));
+
+        days.entry(date).or_default().push(AstronomyDayEvent {
+            r#type,
+            hour: component(0..2)?,
+            min: component(2..4)?,
+            sec: component(4..6)?,
+            isotime: None,
+            offset: None,
+            utctime,
+            altitude: None,
+            azimuth: None,
+            distance: None,
+            illuminated: None,
+            posangle: None,
+        });
+    }
+
+    Ok(days
+        .into_iter()
+        .map(|(date, events)| AstronomyDay {
+            date: DateTime::from(date.format("%Y-%m-%d").to_string()),
+            daylength: None,
+            moonphase: None,
+            events,
+        })
+        .collect())
+}
+
+fn parse_events(input: &str) -> Result<Vec<(usize, Event)>, ParseError> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in input.split('\n').map(|l| l.trim_end_matches('\r')) {
+        match (
+            line.strip_prefix(' ').or_else(|| line.strip_prefix('\t')),
+            lines.last_mut(),
+        ) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.into()),
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<(usize, Event)> = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        let number = index + 1;
+
+        let (name, value) = split_property(line).ok_or_else(|| ParseError {
+            line: number,
+            message: format!("invalid content line: {}", line),
+        })?;
+
+        match (name.to_ascii_uppercase().as_str(), value) {
+            ("BEGIN", v) if v.eq_ignore_ascii_case("VEVENT") => {
+                current = Some((number, Event::default()));
+            }
+            ("END", v) if v.eq_ignore_ascii_case("VEVENT") => {
+                events.extend(current.take());
+            }
+            _ => {
+                if let Some((_, ref mut event)) = current {
+                    event.push(name, value);
+                }
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// Split a content line at the first colon which is not part of a quoted parameter value.
+fn split_property(line: &str) -> Option<(&str, &str)> {
+    let mut quoted = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ':' if !quoted => return Some((&line[..i], &line[i + 1..])),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn property_name(name: &str) -> &str {
+    name.split(';').next().unwrap_or(name)
+}
+
+fn missing(line: usize, property: &str) -> ParseError {
+    ParseError {
+        line,
+        message: format!("missing property {}", property),
+    }
+}
+
+/// Write a content line, folded at 75 octets.
+fn write_line(f: &mut std::fmt::Formatter, line: &str) -> std::fmt::Result {
+    let mut start = 0;
+    let mut limit = 75;
+
+    while line.len() - start > limit {
+        let mut end = start + limit;
+
+        while !line.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        write!(f, "{}\r\n ", &line[start..end])?;
+        start = end;
+        limit = 74;
+    }
+
+    write!(f, "{}\r\n", &line[start..])
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') | Some('N') => result.push('\n'),
+            Some(escaped) => result.push(escaped),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// Split a list value at commas which are not escaped.
+fn split_list(value: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            ',' if !escaped => {
+                items.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => escaped = false,
+        }
+    }
+
+    items.push(&value[start..]);
+    items
+}
+
+fn is_date_only(time: &Time) -> bool {
+    !time.iso.contains('T')
+}
+
+fn date_value(datetime: &DateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}",
+        datetime.year, datetime.month, datetime.day
+    )
+}
+
+fn local_value(datetime: &DateTime) -> String {
+    format!(
+        "{}T{:02}{:02}{:02}",
+        date_value(datetime),
+        datetime.hour,
+        datetime.minute,
+        datetime.second
+    )
+}
+
+fn utc_value(utc: &NaiveDateTime) -> String {
+    utc.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// UTC time of an astronomical event, from its UTC time or its local time and offset.
+fn utc_time(event: &AstronomyDayEvent) -> Option<NaiveDateTime> {
+    match (&event.utctime, &event.isotime, event.offset) {
+        (Some(utc), _, _) => naive_datetime(utc),
+        (None, Some(local), Some(offset)) => {
+            Some(naive_datetime(local)? - Duration::seconds(offset.local_minus_utc().into()))
+        }
+        _ => None,
+    }
+}
+
+/// Identifier and display name of an astronomical object.
+fn object_name(object: &AstronomyObjectType) -> (&'static str, &'static str) {
+    match object {
+        AstronomyObjectType::Sun => ("sun", "Sun"),
+        AstronomyObjectType::Moon => ("moon", "Moon"),
+        AstronomyObjectType::Mercury => ("mercury", "Mercury"),
+        AstronomyObjectType::Venus => ("venus", "Venus"),
+        AstronomyObjectType::Mars => ("mars", "Mars"),
+        AstronomyObjectType::Jupiter => ("jupiter", "Jupiter"),
+        AstronomyObjectType::Saturn => ("saturn", "Saturn"),
+        AstronomyObjectType::Uranus => ("uranus", "Uranus"),
+        AstronomyObjectType::Neptune => ("neptune", "Neptune"),
+        AstronomyObjectType::Pluto => ("pluto", "Pluto"),
+    }
+}
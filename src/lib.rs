@@ -1,13 +1,25 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+use chrono::{DateTime, Duration, Utc};
 use http::{HTTPBaseClient, HTTPClient};
 use maybe_async::maybe_async;
+use middleware::{Middleware, Next};
+use observer::Observer;
 use service::Service;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
 use url::Url;
 
+mod clock;
+mod convert;
+mod credentials;
 mod error;
 mod http;
+#[cfg(feature = "__async")]
+mod paging;
+#[cfg(feature = "tracing")]
+mod trace;
 
 /// Service-related models.
 pub mod models {
@@ -18,16 +30,48 @@ pub mod models {
 pub use error::ApiError;
 
 /// Internal error.
-pub use error::Error;
+pub use error::{Error, ValidationError};
+
+/// Credentials for accessing the API.
+pub use credentials::{Credentials, CredentialsError, CredentialsProvider};
+
+/// Source of the time used to sign requests.
+pub use clock::{Clock, SystemClock};
+
+/// Request signing.
+pub mod auth;
 
 /// Available Time and Date services.
 pub mod service;
 
+/// Concurrent execution of many requests.
+pub mod batch;
+
+/// Observers notified of API calls, e.g. to export metrics.
+pub mod observer;
+
+/// Layers wrapping every API call, e.g. to retry, rate limit or audit requests.
+pub mod middleware;
+
+/// CSV and newline-delimited JSON export of responses.
+pub mod tabular;
+
+#[cfg(feature = "geojson")]
+/// GeoJSON export of places, tide stations and locations.
+pub mod geojson;
+
+#[cfg(feature = "ics")]
+/// iCalendar (RFC 5545) export and import of holidays and astronomical events.
+pub mod ics;
+
 /// Client for accessing the Time and Date APIs.
 pub struct ServiceClient {
     client: HTTPClient,
-    access_key: String,
-    secret_key: String,
+    credentials: Credentials,
+    clock: Box<dyn Clock>,
+    skew: Arc<AtomicI64>,
+    skew_layer: Option<usize>,
+    layers: Vec<Box<dyn Middleware>>,
 }
 
 impl ServiceClient {
@@ -36,46 +80,130 @@ impl ServiceClient {
 
     /// Initialize a new client with an access and secret key.
     pub fn new(access_key: String, secret_key: String) -> Self {
-        Self {
+        Self::with_credentials(Credentials::new(access_key, secret_key))
+    }
+
+    /// Initialize a new client with credentials, e.g. loaded from the environment or a file.
+    pub fn with_credentials(credentials: Credentials) -> Self {
+        let client = Self {
             client: HTTPClient::new(),
-            access_key,
-            secret_key,
+            credentials,
+            clock: Box::new(SystemClock),
+            skew: Default::default(),
+            skew_layer: None,
+            layers: Vec::new(),
+        };
+
+        #[cfg(feature = "tracing")]
+        let client = client.with_layer(middleware::Trace);
+
+        client
+    }
+
+    /// Set the clock used to timestamp requests. Defaults to the system clock.
+    pub fn set_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+
+        self
+    }
+
+    /// Add a layer wrapping every API call. Layers run in the order they are added.
+    pub fn with_layer(mut self, layer: impl Middleware + 'static) -> Self {
+        self.layers.push(Box::new(layer));
+
+        self
+    }
+
+    /// Toggle whether to correct for a skewed clock.
+    ///
+    /// If enabled, a [`SkewCorrection`](middleware::SkewCorrection) layer is added. A request
+    /// which fails authentication while the server reports a time more than 30 seconds off from
+    /// the clock is retried once with the server time. The difference is kept for later requests.
+    ///
+    /// Enabling it again keeps the existing layer. Disabling it removes the layer and the
+    /// correction.
+    pub fn set_skew_correction(mut self, enable: bool) -> Self {
+        match (enable, self.skew_layer) {
+            (true, None) => {
+                let layer = middleware::SkewCorrection::shared(self.skew.clone());
+
+                self.skew_layer = Some(self.layers.len());
+                self.with_layer(layer)
+            }
+            (false, Some(index)) => {
+                self.layers.remove(index);
+                self.skew_layer = None;
+                self.skew.store(0, Ordering::Relaxed);
+
+                self
+            }
+            _ => self,
         }
     }
 
+    /// Set an observer notified after every API call, e.g. to export metrics.
+    ///
+    /// The observer is added as an [`Observe`](middleware::Observe) layer.
+    pub fn set_observer(self, observer: impl Observer + 'static) -> Self {
+        self.with_layer(middleware::Observe::new(observer))
+    }
+
+    /// The correction currently added to the clock.
+    pub fn skew(&self) -> Duration {
+        Duration::seconds(self.skew.load(Ordering::Relaxed))
+    }
+
+    /// The time used to timestamp requests.
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now() + self.skew()
+    }
+
     #[maybe_async]
     async fn call<S: Service>(
         &self,
         request: &S::Request,
     ) -> Result<Result<S::Response, ApiError>, Error> {
-        let mut url = Url::parse(Self::BASE_URL).unwrap();
-        let query = S::build_query(request);
-
-        url.set_path(S::PATH);
-        url.set_query(query.as_deref());
-        url = self.authenticate::<S>(url)?;
+        let request = middleware::Request::new::<S>(request, self.clock.now());
+        let response = Next::new(self, &self.layers).run(request).await?;
 
-        self.client.get::<S>(url).await
+        response.into_parsed::<S>()
     }
 
-    fn authenticate<S: Service>(&self, mut url: Url) -> Result<Url, Error> {
-        use hmac::{Hmac, Mac, NewMac};
-        use sha1::Sha1;
+    /// Sign and send a request at the end of the middleware chain.
+    #[maybe_async]
+    async fn send(&self, request: middleware::Request) -> Result<middleware::Response, Error> {
+        request.validation()?;
 
-        let timestamp = chrono::Utc::now().format("%FT%T");
+        let url = self.signed_url(request.path, request.query.as_deref(), &request.timestamp)?;
 
-        let mut mac = Hmac::<Sha1>::new_from_slice(self.secret_key.as_bytes())?;
+        self.client.get(url).await
+    }
 
-        let message = format!("{}{}{}", self.access_key, S::PATH, timestamp);
-        mac.update(message.as_bytes());
+    /// Build the authenticated URL for a request, timestamped with the current time.
+    fn sign_url<S: Service>(&self, request: &S::Request) -> Result<Url, Error> {
+        let query = S::build_query(request);
 
-        let bytes = mac.finalize().into_bytes();
-        let signature = base64::encode(bytes);
+        self.signed_url(S::PATH, query.as_deref(), &self.now())
+    }
+
+    fn signed_url(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<Url, Error> {
+        let mut url = Url::parse(Self::BASE_URL).unwrap();
+        let signature = auth::sign(&self.credentials, path, timestamp)?;
 
+        url.set_path(path);
+        url.set_query(query);
         url.query_pairs_mut()
-            .append_pair("accesskey", &self.access_key)
+            .append_pair("accesskey", self.credentials.access_key())
             .append_pair("signature", &signature)
-            .append_pair("timestamp", &timestamp.to_string())
+            .append_pair(
+                "timestamp",
+                &timestamp.format(auth::TIMESTAMP_FORMAT).to_string(),
+            )
             .append_pair("version", Self::VERSION);
 
         Ok(url)
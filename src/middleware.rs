@@ -0,0 +1,373 @@
+use crate::observer::{Call, Observer, Outcome};
+use crate::service::{Service, Validate};
+use crate::{http, ApiError, Error, ServiceClient, ValidationError};
+use chrono::{DateTime, Duration, Utc};
+use maybe_async::maybe_async;
+use std::any::Any;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+/// Request passing through the middleware chain. It is signed with the timestamp when sent.
+pub struct Request {
+    /// Path of the service, e.g. "holidays".
+    pub path: &'static str,
+
+    /// Query string, without the access key or signature.
+    pub query: Option<String>,
+
+    /// Time used to sign the request.
+    pub timestamp: DateTime<Utc>,
+
+    check: Check,
+    invalid: Option<ValidationError>,
+}
+
+/// Parse a response body as the response of a service, unless it has been parsed before.
+type Check = fn(&Response) -> Checked;
+
+/// Result of parsing a response body as the response of a service.
+type Parsed<S> = Result<Result<<S as Service>::Response, ApiError>, Error>;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Summary of a parsed response body.
+pub(crate) enum Checked {
+    /// The body is a response of the service.
+    Success,
+
+    /// The body is an error returned from the API.
+    ApiError(Vec<String>),
+
+    /// The body could not be parsed.
+    Failed(Outcome, String),
+}
+
+impl Request {
+    pub(crate) fn new<S: Service>(request: &S::Request, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            path: S::PATH,
+            query: S::build_query(request),
+            timestamp,
+            check: check::<S>,
+            invalid: request.validate().err(),
+        }
+    }
+
+    /// Whether the request passed validation. Invalid requests pass through the chain, so that
+    /// layers can observe them, and fail when they would be sent.
+    pub(crate) fn validation(&self) -> Result<(), ValidationError> {
+        self.invalid.clone().map_or(Ok(()), Err)
+    }
+
+    /// Check whether a response can be parsed as the response of the service. The body is parsed
+    /// once, and the result is kept with the response for later checks and the client.
+    pub(crate) fn check(&self, response: &Response) -> Checked {
+        (self.check)(response)
+    }
+}
+
+fn check<S: Service>(response: &Response) -> Checked {
+    let mut parsed = response.parsed();
+    let parsed = parsed.get_or_insert_with(|| Box::new(http::parse::<S>(&response.body)));
+
+    match parsed.downcast_ref::<Parsed<S>>() {
+        Some(Ok(Ok(_))) => Checked::Success,
+        Some(Ok(Err(e))) => Checked::ApiError(e.errors.clone()),
+        Some(Err(e)) => Checked::Failed(Outcome::of(e), e.to_string()),
+        None => Checked::Failed(Outcome::Deserialize, "response of another service".into()),
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Raw response passing back through the middleware chain.
+///
+/// The body is parsed at most once, when a layer or the client first needs the outcome of the
+/// call. Layers changing the body should build a new response with [`Response::new`].
+pub struct Response {
+    /// Time reported by the server.
+    pub date: Option<DateTime<Utc>>,
+
+    /// Response body.
+    pub body: Vec<u8>,
+
+    /// Number of requests sent. Larger than one if the request was retried.
+    pub attempts: u32,
+
+    parsed: Arc<Mutex<Option<Box<dyn Any + Send>>>>,
+}
+
+impl Response {
+    /// Create a response from a body and the time reported by the server.
+    pub fn new(body: Vec<u8>, date: Option<DateTime<Utc>>) -> Self {
+        Self {
+            date,
+            body,
+            attempts: 1,
+            parsed: Default::default(),
+        }
+    }
+
+    fn parsed(&self) -> MutexGuard<'_, Option<Box<dyn Any + Send>>> {
+        self.parsed.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The typed response of a service, parsed from the body unless a layer already did.
+    pub(crate) fn into_parsed<S: Service>(self) -> Parsed<S> {
+        let parsed = self.parsed().take();
+
+        match parsed.map(|parsed| parsed.downcast::<Parsed<S>>()) {
+            Some(Ok(parsed)) => *parsed,
+            _ => http::parse::<S>(&self.body),
+        }
+    }
+
+    /// The error returned from the API, if any.
+    pub fn api_error(&self) -> Option<ApiError> {
+        serde_json::from_slice(&self.body).ok()
+    }
+}
+
+#[maybe_async]
+/// Layer wrapping every API call, e.g. to retry, cache, log or audit requests.
+///
+/// Layers run in the order they were added to the client, and each calls [`Next::run`] to pass
+/// the request on to the next layer. The last layer sends the request.
+///
+/// Example:
+/// ```
+/// use libtad_rs::middleware::{Middleware, Next, Request, Response};
+/// use libtad_rs::{Error, ServiceClient};
+///
+/// struct Audit;
+///
+/// #[maybe_async::maybe_async]
+/// impl Middleware for Audit {
+///     async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+///         println!("{} {:?}", request.path, request.query);
+///         next.run(request).await
+///     }
+/// }
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into()).with_layer(Audit);
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Handle a request, passing it on to `next`.
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error>;
+}
+
+#[derive(Clone, Copy)]
+/// The rest of the middleware chain.
+pub struct Next<'a> {
+    client: &'a ServiceClient,
+    layers: &'a [Box<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(client: &'a ServiceClient, layers: &'a [Box<dyn Middleware>]) -> Self {
+        Self { client, layers }
+    }
+
+    #[maybe_async]
+    /// Pass the request on to the next layer, or send it if this is the last layer.
+    pub async fn run(self, request: Request) -> Result<Response, Error> {
+        match self.layers.split_first() {
+            Some((layer, layers)) => {
+                let next = Next::new(self.client, layers);
+                layer.handle(request, next).await
+            }
+            None => self.client.send(request).await,
+        }
+    }
+}
+
+/// Layer correcting for a skewed clock.
+///
+/// A request which fails authentication while the server reports a time more than 30 seconds off
+/// from the request timestamp is retried once with the server time. The difference is kept for
+/// later requests.
+#[derive(Default)]
+pub struct SkewCorrection {
+    skew: Arc<AtomicI64>,
+}
+
+impl SkewCorrection {
+    /// Smallest difference from the server time, in seconds, which is corrected for.
+    const MIN_SKEW: i64 = 30;
+
+    /// Create a layer without any correction.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub(crate) fn shared(skew: Arc<AtomicI64>) -> Self {
+        Self { skew }
+    }
+
+    /// The correction currently added to request timestamps.
+    pub fn skew(&self) -> Duration {
+        Duration::seconds(self.skew.load(Ordering::Relaxed))
+    }
+
+    /// Learn the clock skew from a response which failed authentication. Returns whether the
+    /// skew changed, in which case the request should be retried.
+    fn learn(&self, request: &Request, response: &Response) -> bool {
+        let (errors, date) = match (request.check(response), response.date) {
+            (Checked::ApiError(errors), Some(date)) => (errors, date),
+            _ => return false,
+        };
+
+        let authentication_failed = errors.iter().any(|e| {
+            let e = e.to_lowercase();
+            e.contains("authorization") || e.contains("signature") || e.contains("timestamp")
+        });
+
+        let skew = (date - request.timestamp).num_seconds();
+
+        if !authentication_failed || (skew - self.skew().num_seconds()).abs() < Self::MIN_SKEW {
+            return false;
+        }
+
+        self.skew.store(skew, Ordering::Relaxed);
+
+        true
+    }
+}
+
+#[maybe_async]
+impl Middleware for SkewCorrection {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        let timestamp = request.timestamp;
+        let corrected = |mut request: Request| {
+            request.timestamp = timestamp + self.skew();
+            request
+        };
+
+        let response = next.run(corrected(request.clone())).await?;
+
+        if !self.learn(&request, &response) {
+            return Ok(response);
+        }
+
+        let mut retried = next.run(corrected(request)).await?;
+        retried.attempts += response.attempts;
+
+        Ok(retried)
+    }
+}
+
+/// Layer notifying an [`Observer`] after every call.
+pub struct Observe<O> {
+    observer: O,
+}
+
+impl<O: Observer> Observe<O> {
+    /// Create a layer notifying `observer`.
+    pub fn new(observer: O) -> Self {
+        Self { observer }
+    }
+}
+
+#[maybe_async]
+impl<O: Observer> Middleware for Observe<O> {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        let start = Instant::now();
+        let response = next.run(request.clone()).await;
+
+        self.observer
+            .observe(&Call::new(&request, start.elapsed(), &response));
+
+        response
+    }
+}
+
+/// Layer limiting the rate of requests. Requests are spaced evenly and wait for their turn.
+pub struct RateLimit {
+    pacer: Pacer,
+}
+
+impl RateLimit {
+    /// Allow at most `requests` requests per `period`.
+    pub fn new(requests: u32, period: std::time::Duration) -> Self {
+        Self {
+            pacer: Pacer::new(Some(period / requests.max(1))),
+        }
+    }
+}
+
+#[maybe_async]
+impl Middleware for RateLimit {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        if let Some(wait) = self.pacer.reserve() {
+            sleep(wait).await;
+        }
+
+        next.run(request).await
+    }
+}
+
+#[cfg(feature = "__sync")]
+fn sleep(duration: std::time::Duration) {
+    std::thread::sleep(duration)
+}
+
+#[cfg(feature = "__async")]
+async fn sleep(duration: std::time::Duration) {
+    tokio::time::sleep(duration).await
+}
+
+#[cfg(feature = "tracing")]
+/// Layer opening a `tracing` span for every call, with its path, query, latency and outcome.
+///
+/// It is added to every client when the "tracing" feature is enabled.
+pub struct Trace;
+
+#[cfg(feature = "tracing")]
+#[maybe_async]
+impl Middleware for Trace {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        let start = Instant::now();
+        let span = crate::trace::span(&request);
+
+        #[cfg(feature = "__sync")]
+        let response = {
+            let _entered = span.enter();
+            next.run(request.clone())
+        };
+
+        #[cfg(feature = "__async")]
+        let response =
+            tracing::Instrument::instrument(next.run(request.clone()), span.clone()).await;
+
+        crate::trace::record(&span, &request, start.elapsed(), &response);
+
+        response
+    }
+}
+
+/// Hands out evenly spaced start times to requests.
+pub(crate) struct Pacer {
+    interval: Option<std::time::Duration>,
+    next: Mutex<Option<Instant>>,
+}
+
+impl Pacer {
+    pub(crate) fn new(interval: Option<std::time::Duration>) -> Self {
+        Self {
+            interval,
+            next: Mutex::new(None),
+        }
+    }
+
+    /// Reserve the next slot, returning how long to wait before it starts.
+    pub(crate) fn reserve(&self) -> Option<std::time::Duration> {
+        let interval = self.interval?;
+        let now = Instant::now();
+        let mut next = self.next.lock().unwrap();
+
+        let start = next.map_or(now, |next| next.max(now));
+        *next = Some(start + interval);
+
+        Some(start - now).filter(|wait| !wait.is_zero())
+    }
+}
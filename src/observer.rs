@@ -0,0 +1,172 @@
+use crate::middleware::{Checked, Request, Response};
+use crate::Error;
+use std::time::Duration;
+
+/// Observer notified after every API call, e.g. to export metrics.
+///
+/// It is implemented for closures taking a [`Call`].
+///
+/// Example:
+/// ```
+/// use libtad_rs::observer::Call;
+/// use libtad_rs::ServiceClient;
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into())
+///     .set_observer(|call: &Call| {
+///         println!("{} {} {:?}", call.path, call.outcome.as_str(), call.latency);
+///     });
+/// ```
+pub trait Observer: Send + Sync {
+    /// Called once per API call. Retries made by layers added after the observer are included.
+    fn observe(&self, call: &Call);
+}
+
+impl<F> Observer for F
+where
+    F: Fn(&Call) + Send + Sync,
+{
+    fn observe(&self, call: &Call) {
+        self(call)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Summary of a finished API call.
+pub struct Call {
+    /// Path of the service, e.g. "holidays".
+    pub path: &'static str,
+
+    /// Outcome of the call.
+    pub outcome: Outcome,
+
+    /// Time spent on the call, including retries.
+    pub latency: Duration,
+
+    /// Number of requests sent. Larger than one if the request was retried.
+    pub attempts: u32,
+
+    /// Size of the response body in bytes, if a response was received.
+    pub size: Option<usize>,
+}
+
+impl Call {
+    pub(crate) fn new(
+        request: &Request,
+        latency: Duration,
+        response: &Result<Response, Error>,
+    ) -> Self {
+        let outcome = match response.as_ref().map(|r| request.check(r)) {
+            Ok(Checked::Success) => Outcome::Success,
+            Ok(Checked::ApiError(_)) => Outcome::ApiError,
+            Ok(Checked::Failed(outcome, _)) => outcome,
+            Err(e) => Outcome::of(e),
+        };
+
+        let (attempts, size) = match response {
+            Ok(response) => (response.attempts, Some(response.body.len())),
+            Err(_) => (1, None),
+        };
+
+        Self {
+            path: request.path,
+            outcome,
+            latency,
+            attempts,
+            size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Outcome of an API call, classified by error.
+pub enum Outcome {
+    /// The API returned a response.
+    Success,
+
+    /// The API returned an [`ApiError`](crate::ApiError).
+    ApiError,
+
+    /// The request failed in the HTTP client.
+    Http,
+
+    /// The response could not be deserialized.
+    Deserialize,
+
+    /// The request could not be signed.
+    Signature,
+
+    /// The request failed validation.
+    Validation,
+}
+
+impl Outcome {
+    pub(crate) fn of(error: &Error) -> Self {
+        match error {
+            Error::Http(_) => Self::Http,
+            Error::Deserialize(_) => Self::Deserialize,
+            Error::Signature(_) => Self::Signature,
+            Error::Validation(_) => Self::Validation,
+        }
+    }
+
+    /// Label for the outcome, e.g. "api_error".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::ApiError => "api_error",
+            Self::Http => "http",
+            Self::Deserialize => "deserialize",
+            Self::Signature => "signature",
+            Self::Validation => "validation",
+        }
+    }
+
+    /// Whether the call failed.
+    pub fn is_error(&self) -> bool {
+        *self != Self::Success
+    }
+}
+
+#[cfg(feature = "metrics")]
+/// Observer reporting calls to the [`metrics`] crate.
+///
+/// The following metrics are recorded, labelled with the service `path`:
+/// - `libtad_requests_total`: Counter of calls, also labelled with the `outcome`.
+/// - `libtad_retries_total`: Counter of retried requests.
+/// - `libtad_request_duration_seconds`: Histogram of call latencies.
+/// - `libtad_response_size_bytes`: Histogram of response sizes.
+///
+/// Example:
+/// ```
+/// use libtad_rs::observer::MetricsObserver;
+/// use libtad_rs::ServiceClient;
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into())
+///     .set_observer(MetricsObserver);
+/// ```
+pub struct MetricsObserver;
+
+#[cfg(feature = "metrics")]
+impl Observer for MetricsObserver {
+    fn observe(&self, call: &Call) {
+        metrics::counter!(
+            "libtad_requests_total",
+            "path" => call.path,
+            "outcome" => call.outcome.as_str(),
+        )
+        .increment(1);
+
+        if call.attempts > 1 {
+            metrics::counter!("libtad_retries_total", "path" => call.path)
+                .increment(u64::from(call.attempts - 1));
+        }
+
+        metrics::histogram!("libtad_request_duration_seconds", "path" => call.path)
+            .record(call.latency.as_secs_f64());
+
+        if let Some(size) = call.size {
+            metrics::histogram!("libtad_response_size_bytes", "path" => call.path)
+                .record(size as f64);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{ApiError, Error};
+use futures::stream::{self, Stream};
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::hash::Hash;
+
+/// Lazily fetch pages one at a time and yield their items.
+///
+/// Every item is paired with a key, and items whose key was already part of the previous page are
+/// skipped, so adjacent pages may overlap at their boundaries. The stream ends after the first
+/// error.
+pub(crate) fn paginate<P, K, T, F, Fut>(
+    pages: Vec<P>,
+    fetch: F,
+) -> impl Stream<Item = Result<Result<T, ApiError>, Error>>
+where
+    K: Eq + Hash,
+    F: FnMut(P) -> Fut,
+    Fut: Future<Output = Result<Result<Vec<(K, T)>, ApiError>, Error>>,
+{
+    let state = Pager {
+        pages: pages.into_iter(),
+        fetch,
+        buffer: VecDeque::new(),
+        previous: HashSet::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(Ok(item)), state));
+            }
+
+            if state.done {
+                return None;
+            }
+
+            let page = state.pages.next()?;
+
+            match (state.fetch)(page).await {
+                Ok(Ok(items)) => {
+                    let mut keys = HashSet::with_capacity(items.len());
+
+                    for (key, item) in items {
+                        if !state.previous.contains(&key) {
+                            state.buffer.push_back(item);
+                        }
+
+                        keys.insert(key);
+                    }
+
+                    state.previous = keys;
+                }
+                Ok(Err(e)) => {
+                    state.done = true;
+                    return Some((Ok(Err(e)), state));
+                }
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+struct Pager<I, F, K, T> {
+    pages: I,
+    fetch: F,
+    buffer: VecDeque<T>,
+    previous: HashSet<K>,
+    done: bool,
+}
@@ -1,3 +1,6 @@
+use crate::convert::naive_datetime;
+use crate::models::time::DateTime;
+use crate::ValidationError;
 use serde::{de::DeserializeOwned, Serialize};
 
 /// Astronomy API.
@@ -27,10 +30,10 @@ pub trait Service {
     const PATH: &'static str;
 
     /// Request type for a service.
-    type Request: Serialize;
+    type Request: Serialize + Validate;
 
     /// Response type for a service.
-    type Response: DeserializeOwned;
+    type Response: DeserializeOwned + Send + 'static;
 
     #[doc(hidden)]
     fn build_query(request: &Self::Request) -> Option<String> {
@@ -38,9 +41,41 @@ pub trait Service {
     }
 }
 
-#[derive(Default)]
+/// Trait implemented by all requests, checking for invalid combinations of arguments.
+///
+/// Requests are validated by the client before they are sent. Invalid requests still pass through
+/// the middleware chain, so observers see the validation error.
+///
+/// Example:
+/// ```
+/// use libtad_rs::service::{onthisday::OnThisDayRequest, Validate};
+///
+/// let request = OnThisDayRequest::new().set_month(2).set_day(30);
+///
+/// assert!(request.validate().is_err());
+/// ```
+pub trait Validate {
+    /// Check the request, returning the first problem found.
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+/// Check that a time interval does not end before it starts.
+pub(crate) fn validate_range(startdt: &DateTime, enddt: &DateTime) -> Result<(), ValidationError> {
+    match (naive_datetime(startdt), naive_datetime(enddt)) {
+        (Some(start), Some(end)) if end < start => Err(ValidationError::new(
+            "enddt",
+            format!("{} is before startdt {}", enddt, startdt),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Default, Clone, Copy)]
 /// Marker to illustrate that a required argument is missing.
 pub struct RequiredArgument;
 
+#[derive(Clone, Copy)]
 /// Marker to ensure that a required request argument has been provided.
 pub struct ProvidedArgument;
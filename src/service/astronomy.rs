@@ -1,6 +1,11 @@
 use super::Service;
 use crate::{ApiError, Error, ServiceClient};
+use chrono::Duration;
 use maybe_async::maybe_async;
+use url::Url;
+
+#[cfg(feature = "__async")]
+use crate::models::astronomy::{AstronomyDay, AstronomyObjectType};
 
 mod request;
 mod response;
@@ -11,6 +16,12 @@ pub use request::{AstroEventRequest, AstroPositionRequest};
 /// Astronomy API responses.
 pub use response::AstronomyResponse;
 
+/// Longest date range, in days, requested at once when splitting astro event requests.
+const MAX_EVENT_RANGE_DAYS: i64 = 31;
+
+/// Most points in time requested at once when splitting astro position requests.
+const MAX_POSITION_INTERVALS: usize = 50;
+
 struct AstroEventService;
 struct AstroPositionService;
 
@@ -40,6 +51,12 @@ impl ServiceClient {
         self.call::<AstroEventService>(request).await
     }
 
+    /// Signed URL for a request to the *Astro Event* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_astro_events_url(&self, request: &AstroEventRequest) -> Result<Url, Error> {
+        self.sign_url::<AstroEventService>(request)
+    }
+
     #[maybe_async]
     /// The *Astro Position* service can be used to retrieve the altitude, azimuth and distance to
     /// the Moon and the Sun for all locations in our database. The service also returns the moon
@@ -54,4 +71,88 @@ impl ServiceClient {
     ) -> Result<Result<AstronomyResponse, ApiError>, Error> {
         self.call::<AstroPositionService>(request).await
     }
+
+    /// Signed URL for a request to the *Astro Position* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_astro_position_url(&self, request: &AstroPositionRequest) -> Result<Url, Error> {
+        self.sign_url::<AstroPositionService>(request)
+    }
+
+    #[maybe_async]
+    /// Retrieve astronomical events for a date range of any length.
+    ///
+    /// Long ranges are split into several requests to the *Astro Event* service, and the results
+    /// are combined into a single response with one entry per day.
+    pub async fn get_astro_events_range(
+        &self,
+        request: &AstroEventRequest,
+    ) -> Result<Result<AstronomyResponse, ApiError>, Error> {
+        let mut response = AstronomyResponse { locations: vec![] };
+
+        for chunk in request.split(Duration::days(MAX_EVENT_RANGE_DAYS)) {
+            match self.get_astro_events(&chunk).await? {
+                Ok(r) => response.merge(r),
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+
+        Ok(Ok(response))
+    }
+
+    #[maybe_async]
+    /// Retrieve astronomical positions for any number of points in time.
+    ///
+    /// Many intervals are split into several requests to the *Astro Position* service, and the
+    /// results are combined into a single response.
+    pub async fn get_astro_position_range(
+        &self,
+        request: &AstroPositionRequest,
+    ) -> Result<Result<AstronomyResponse, ApiError>, Error> {
+        let mut response = AstronomyResponse { locations: vec![] };
+
+        for chunk in request.split(MAX_POSITION_INTERVALS) {
+            match self.get_astro_position(&chunk).await? {
+                Ok(r) => response.merge(r),
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+
+        Ok(Ok(response))
+    }
+
+    #[cfg(feature = "__async")]
+    /// Stream astronomical events day by day for every location and object in the request. Every
+    /// day is paired with the id of its location and its object.
+    ///
+    /// The date range is fetched lazily in parts, so long ranges can be processed without waiting
+    /// for all data. The stream ends after the first error.
+    pub fn stream_astro_days(
+        &self,
+        request: &AstroEventRequest,
+    ) -> impl futures::Stream<
+        Item = Result<Result<(String, AstronomyObjectType, AstronomyDay), ApiError>, Error>,
+    > + '_ {
+        let pages = request.split(Duration::days(MAX_EVENT_RANGE_DAYS));
+
+        crate::paging::paginate(pages, move |page: AstroEventRequest| async move {
+            let response = self.get_astro_events(&page).await?;
+
+            Ok(response.map(|response| {
+                let mut days = Vec::new();
+
+                for location in response.locations {
+                    for object in location.astronomy.objects {
+                        for day in object.days.unwrap_or_default() {
+                            let date = (day.date.year, day.date.month, day.date.day);
+                            let key = (location.id.clone(), object.name, date);
+
+                            days.push((key, (location.id.clone(), object.name, day)));
+                        }
+                    }
+                }
+
+                days
+            }))
+        })
+    }
 }
@@ -1,8 +1,11 @@
+use crate::convert::split_range;
 use crate::models::{
     astronomy::{AstronomyEventClass, AstronomyObjectType},
     time::DateTime,
 };
-use crate::service::{ProvidedArgument, RequiredArgument};
+use crate::service::{validate_range, ProvidedArgument, RequiredArgument, Validate};
+use crate::ValidationError;
+use chrono::Duration;
 use serde::Serialize;
 
 macro_rules! return_type {
@@ -25,7 +28,7 @@ macro_rules! return_type {
     };
 }
 
-#[derive(Default, Serialize)]
+#[derive(Default, Clone, Serialize)]
 /// Astro Event API request.
 ///
 /// Request is validated at compile time when supplied to the client.
@@ -70,6 +73,24 @@ impl AstroEventRequest {
     pub fn new() -> AstroEventRequest<RequiredArgument, RequiredArgument, RequiredArgument> {
         Default::default()
     }
+
+    /// Split the request into requests covering at most `span` of the date range each.
+    pub(crate) fn split(&self, span: Duration) -> Vec<Self> {
+        let enddt = match self.enddt {
+            Some(enddt) => enddt,
+            None => return vec![self.clone()],
+        };
+
+        split_range(&self.startdt, &enddt, span)
+            .into_iter()
+            .map(|(startdt, enddt)| {
+                let mut request = self.clone();
+                request.startdt = startdt;
+                request.enddt = Some(enddt);
+                request
+            })
+            .collect()
+    }
 }
 
 impl<A, B, C> AstroEventRequest<A, B, C> {
@@ -122,7 +143,7 @@ impl<A, B, C> AstroEventRequest<A, B, C> {
 
     /// Set the end date for the request.
     pub fn set_enddt(mut self, enddt: DateTime) -> Self {
-        self.enddt.insert(enddt);
+        self.enddt = Some(enddt);
 
         self
     }
@@ -132,7 +153,7 @@ impl<A, B, C> AstroEventRequest<A, B, C> {
         if let Some(ref mut types) = self.types {
             types.push(event_type);
         } else {
-            self.types.insert(vec![event_type]);
+            self.types = Some(vec![event_type]);
         }
 
         self
@@ -140,36 +161,45 @@ impl<A, B, C> AstroEventRequest<A, B, C> {
 
     /// Toggle whether to return longitude and latitude for the geo object.
     pub fn set_geo(mut self, enable: bool) -> Self {
-        self.geo.insert(enable.into());
+        self.geo = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to add time stamps in local time to all events.
     pub fn set_isotime(mut self, enable: bool) -> Self {
-        self.isotime.insert(enable.into());
+        self.isotime = Some(enable.into());
 
         self
     }
 
     /// Set the request language for the request.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Set search radius for translating coordinates to locations.
     pub fn set_radius(mut self, radius: i32) -> Self {
-        self.radius.insert(radius);
+        self.radius = Some(radius);
 
         self
     }
 
     /// Toggle whether to add time stamps in RequiredArgumentTC to all events.
     pub fn set_utctime(mut self, enable: bool) -> Self {
-        self.utctime.insert(enable.into());
+        self.utctime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for AstroEventRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self.enddt {
+            Some(ref enddt) => validate_range(&self.startdt, enddt),
+            None => Ok(()),
+        }
+    }
+}
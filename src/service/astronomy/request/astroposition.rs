@@ -1,5 +1,5 @@
 use crate::models::{astronomy::AstronomyObjectType, time::DateTime};
-use crate::service::{ProvidedArgument, RequiredArgument};
+use crate::service::{ProvidedArgument, RequiredArgument, Validate};
 use serde::Serialize;
 
 macro_rules! return_type {
@@ -20,7 +20,7 @@ macro_rules! return_type {
     };
 }
 
-#[derive(Default, Serialize)]
+#[derive(Default, Clone, Serialize)]
 /// Astro Position API request.
 ///
 /// Request is validated at compile time when supplied to the client.
@@ -63,6 +63,22 @@ impl AstroPositionRequest {
     pub fn new() -> AstroPositionRequest<RequiredArgument, RequiredArgument, RequiredArgument> {
         Default::default()
     }
+
+    /// Split the request into requests with at most `size` intervals each.
+    pub(crate) fn split(&self, size: usize) -> Vec<Self> {
+        if self.interval.len() <= size {
+            return vec![self.clone()];
+        }
+
+        self.interval
+            .chunks(size)
+            .map(|interval| {
+                let mut request = self.clone();
+                request.interval = interval.to_vec();
+                request
+            })
+            .collect()
+    }
 }
 
 impl<A, B, C> AstroPositionRequest<A, B, C> {
@@ -128,36 +144,38 @@ impl<A, B, C> AstroPositionRequest<A, B, C> {
 
     /// Toggle whether intervals should be considered local time or UTC time.
     pub fn set_localtime(mut self, enable: bool) -> Self {
-        self.localtime.insert(enable.into());
+        self.localtime = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to add time stamps in UTC to all events.
     pub fn set_utctime(mut self, enable: bool) -> Self {
-        self.utctime.insert(enable.into());
+        self.utctime = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to add time stamps in local time to all events.
     pub fn set_isotime(mut self, enable: bool) -> Self {
-        self.isotime.insert(enable.into());
+        self.isotime = Some(enable.into());
 
         self
     }
 
     /// Set the request language for the request.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Set search radius for translating coordinates to locations.
     pub fn set_radius(mut self, radius: i32) -> Self {
-        self.radius.insert(radius);
+        self.radius = Some(radius);
 
         self
     }
 }
+
+impl Validate for AstroPositionRequest {}
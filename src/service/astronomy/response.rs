@@ -1,4 +1,4 @@
-use crate::models::astronomy::AstronomyLocation;
+use crate::models::astronomy::{AstronomyLocation, AstronomyObjectDetails};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -7,3 +7,53 @@ pub struct AstronomyResponse {
     /// Information for the requested locations.
     pub locations: Vec<AstronomyLocation>,
 }
+
+impl AstronomyResponse {
+    /// Merge a response for an adjacent range into this one. Days which are already present are
+    /// skipped, so ranges may overlap at their boundaries.
+    pub(crate) fn merge(&mut self, other: Self) {
+        for location in other.locations {
+            let existing = self
+                .locations
+                .iter_mut()
+                .find(|l| l.id == location.id && l.matchparam == location.matchparam);
+
+            match existing {
+                Some(existing) => {
+                    for object in location.astronomy.objects {
+                        merge_object(&mut existing.astronomy.objects, object);
+                    }
+                }
+                None => self.locations.push(location),
+            }
+        }
+    }
+}
+
+fn merge_object(objects: &mut Vec<AstronomyObjectDetails>, object: AstronomyObjectDetails) {
+    let existing = match objects.iter_mut().find(|o| o.name == object.name) {
+        Some(existing) => existing,
+        None => return objects.push(object),
+    };
+
+    if let Some(days) = object.days {
+        let existing_days = existing.days.get_or_insert_with(Vec::new);
+
+        for day in days {
+            if !existing_days.iter().any(|d| d.date == day.date) {
+                existing_days.push(day);
+            }
+        }
+    }
+
+    if let Some(results) = object.results {
+        existing
+            .results
+            .get_or_insert_with(Vec::new)
+            .extend(results);
+    }
+
+    if existing.current.is_none() {
+        existing.current = object.current;
+    }
+}
@@ -1,9 +1,11 @@
 use super::Service;
 use crate::{ApiError, Error, ServiceClient};
 use maybe_async::maybe_async;
+use url::Url;
 
 mod request;
 mod response;
+mod working_hours;
 
 /// Date Calculator API requests.
 pub use request::{BusinessDateRequest, BusinessDurationRequest};
@@ -11,6 +13,9 @@ pub use request::{BusinessDateRequest, BusinessDurationRequest};
 /// Date Calculator API responses.
 pub use response::{BusinessDateResponse, BusinessDurationResponse};
 
+/// Opening hours for business hour calculations.
+pub use working_hours::WorkingHours;
+
 struct BusinessDateService;
 struct BusinessDurationService;
 
@@ -38,6 +43,12 @@ impl ServiceClient {
         self.call::<BusinessDateService>(request).await
     }
 
+    /// Signed URL for a request to the *Business Date* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn calculate_business_date_url(&self, request: &BusinessDateRequest) -> Result<Url, Error> {
+        self.sign_url::<BusinessDateService>(request)
+    }
+
     #[maybe_async]
     /// The *BusinessDuration* service can be used to calculate the number of business days between a specified start date and end date.
     pub async fn calculate_business_duration(
@@ -46,4 +57,13 @@ impl ServiceClient {
     ) -> Result<Result<BusinessDurationResponse, ApiError>, Error> {
         self.call::<BusinessDurationService>(request).await
     }
+
+    /// Signed URL for a request to the *Business Duration* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn calculate_business_duration_url(
+        &self,
+        request: &BusinessDurationRequest,
+    ) -> Result<Url, Error> {
+        self.sign_url::<BusinessDurationService>(request)
+    }
 }
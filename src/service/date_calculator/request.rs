@@ -1,5 +1,28 @@
+use crate::ValidationError;
+use libtad_models::date_calculator::BusinessDaysFilterType;
+
 mod businessdate;
 mod businessduration;
 
 pub use businessdate::BusinessDateRequest;
 pub use businessduration::BusinessDurationRequest;
+
+/// Check that the `All` filter is only used when including days.
+fn validate_filter(
+    filter: &Option<Vec<BusinessDaysFilterType>>,
+    include: Option<u8>,
+) -> Result<(), ValidationError> {
+    let all = filter
+        .iter()
+        .flatten()
+        .any(|f| matches!(f, BusinessDaysFilterType::All));
+
+    if all && include != Some(1) {
+        return Err(ValidationError::new(
+            "filter",
+            "the all filter is only supported when include is set",
+        ));
+    }
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use crate::service::{ProvidedArgument, RequiredArgument};
+use super::validate_filter;
+use crate::service::{ProvidedArgument, RequiredArgument, Validate};
+use crate::ValidationError;
 use libtad_models::{
     date_calculator::{BusinessDaysFilterType, BusinessDaysOperatorType},
     time::DateTime,
@@ -77,7 +79,7 @@ impl<A, B, C> BusinessDateRequest<A, B, C> {
         mut self,
         placeid: impl Into<String>,
     ) -> BusinessDateRequest<ProvidedArgument, B, C> {
-        self.placeid.insert(placeid.into());
+        self.placeid = Some(placeid.into());
 
         return_type!(self)
     }
@@ -87,21 +89,21 @@ impl<A, B, C> BusinessDateRequest<A, B, C> {
         mut self,
         country: impl Into<String>,
     ) -> BusinessDateRequest<ProvidedArgument, B, C> {
-        self.country.insert(country.into());
+        self.country = Some(country.into());
 
         return_type!(self)
     }
 
     /// Set state for the given country.
     pub fn set_state(mut self, state: impl Into<String>) -> Self {
-        self.state.insert(state.into());
+        self.state = Some(state.into());
 
         self
     }
 
     /// Set start date for the request.
     pub fn set_startdt(mut self, startdt: DateTime) -> BusinessDateRequest<A, ProvidedArgument, C> {
-        self.startdt.insert(startdt);
+        self.startdt = Some(startdt);
 
         return_type!(self)
     }
@@ -122,7 +124,7 @@ impl<A, B, C> BusinessDateRequest<A, B, C> {
 
     /// Toggle whether the result should be calculated by including instead of excluding days.
     pub fn set_include(mut self, enable: bool) -> Self {
-        self.include.insert(enable.into());
+        self.include = Some(enable.into());
 
         self
     }
@@ -132,7 +134,7 @@ impl<A, B, C> BusinessDateRequest<A, B, C> {
         if let Some(ref mut filters) = self.filter {
             filters.push(filter);
         } else {
-            self.filter.insert(vec![filter]);
+            self.filter = Some(vec![filter]);
         }
 
         self
@@ -140,14 +142,14 @@ impl<A, B, C> BusinessDateRequest<A, B, C> {
 
     /// Set the service to add days.
     pub fn set_addition(mut self) -> Self {
-        self.op.insert(BusinessDaysOperatorType::Add);
+        self.op = Some(BusinessDaysOperatorType::Add);
 
         self
     }
 
     /// Set the service to subtract days.
     pub fn set_subtraction(mut self) -> Self {
-        self.op.insert(BusinessDaysOperatorType::Subtract);
+        self.op = Some(BusinessDaysOperatorType::Subtract);
 
         self
     }
@@ -155,22 +157,38 @@ impl<A, B, C> BusinessDateRequest<A, B, C> {
     /// Set how many times the calculation should be repeated.
     /// Only applicable when the days parameter has exactly one number.
     pub fn set_repeat(mut self, repeat: i32) -> Self {
-        self.repeat.insert(repeat);
+        self.repeat = Some(repeat);
 
         self
     }
 
     /// Set request language.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Toggle whether to include verbose time stamps.
     pub fn set_verbosetime(mut self, enable: bool) -> Self {
-        self.verbosetime.insert(enable.into());
+        self.verbosetime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for BusinessDateRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.repeat.is_some() && self.days.len() != 1 {
+            return Err(ValidationError::new(
+                "repeat",
+                format!(
+                    "repeat requires exactly one days value, got {}",
+                    self.days.len()
+                ),
+            ));
+        }
+
+        validate_filter(&self.filter, self.include)
+    }
+}
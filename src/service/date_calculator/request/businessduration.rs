@@ -1,4 +1,6 @@
-use crate::service::{ProvidedArgument, RequiredArgument};
+use super::validate_filter;
+use crate::service::{validate_range, ProvidedArgument, RequiredArgument, Validate};
+use crate::ValidationError;
 use libtad_models::{date_calculator::BusinessDaysFilterType, time::DateTime};
 use serde::Serialize;
 
@@ -73,7 +75,7 @@ impl<A, B, C> BusinessDurationRequest<A, B, C> {
         mut self,
         placeid: impl Into<String>,
     ) -> BusinessDurationRequest<ProvidedArgument, B, C> {
-        self.placeid.insert(placeid.into());
+        self.placeid = Some(placeid.into());
 
         return_type!(self)
     }
@@ -83,14 +85,14 @@ impl<A, B, C> BusinessDurationRequest<A, B, C> {
         mut self,
         country: impl Into<String>,
     ) -> BusinessDurationRequest<ProvidedArgument, B, C> {
-        self.country.insert(country.into());
+        self.country = Some(country.into());
 
         return_type!(self)
     }
 
     /// Set state for the given country.
     pub fn set_state(mut self, state: impl Into<String>) -> Self {
-        self.state.insert(state.into());
+        self.state = Some(state.into());
 
         self
     }
@@ -114,7 +116,7 @@ impl<A, B, C> BusinessDurationRequest<A, B, C> {
 
     /// Toggle whether the result should be calculated by including instead of excluding days.
     pub fn set_include(mut self, enable: bool) -> Self {
-        self.include.insert(enable.into());
+        self.include = Some(enable.into());
 
         self
     }
@@ -124,7 +126,7 @@ impl<A, B, C> BusinessDurationRequest<A, B, C> {
         if let Some(ref mut filters) = self.filter {
             filters.push(filter);
         } else {
-            self.filter.insert(vec![filter]);
+            self.filter = Some(vec![filter]);
         }
 
         self
@@ -132,22 +134,29 @@ impl<A, B, C> BusinessDurationRequest<A, B, C> {
 
     /// Set whether or not the last date should be counted in the result.
     pub fn set_includelastdate(mut self, enable: bool) -> Self {
-        self.includelastdate.insert(enable.into());
+        self.includelastdate = Some(enable.into());
 
         self
     }
 
     /// Set request language.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Toggle whether to include verbose time stamps.
     pub fn set_verbosetime(mut self, enable: bool) -> Self {
-        self.verbosetime.insert(enable.into());
+        self.verbosetime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for BusinessDurationRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_range(&self.startdt, &self.enddt)?;
+        validate_filter(&self.filter, self.include)
+    }
+}
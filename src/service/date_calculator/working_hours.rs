@@ -0,0 +1,290 @@
+use crate::convert::{naive_date, naive_datetime};
+use crate::models::{
+    holidays::{Holiday, HolidayType},
+    places::Location,
+    time::{DateTime, TimeChange, TimeZone},
+};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use std::collections::BTreeSet;
+
+type Instant = chrono::DateTime<Utc>;
+
+#[derive(Debug, Clone, Default)]
+/// Opening hours of a location, used to calculate business hours between two points in time.
+///
+/// Opening hours are given in local time. Holidays close the location for the whole day, except
+/// for half day holidays which use the half day opening hours. Local time is resolved from the
+/// time zone and time changes of the location, so that days with a daylight savings time
+/// transition are counted with their actual length.
+///
+/// Example:
+/// ```
+/// use chrono::{NaiveTime, TimeZone, Utc};
+/// use libtad_rs::service::date_calculator::WorkingHours;
+///
+/// let hours = WorkingHours::new().with_weekdays(
+///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+/// );
+///
+/// let start = Utc.with_ymd_and_hms(2021, 9, 10, 15, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2021, 9, 13, 10, 0, 0).unwrap();
+///
+/// assert_eq!(hours.business_duration(&start, &end).num_hours(), 3);
+/// ```
+pub struct WorkingHours {
+    days: [Option<(NaiveTime, NaiveTime)>; 7],
+    half_day: Option<(NaiveTime, NaiveTime)>,
+    closed: BTreeSet<NaiveDate>,
+    half_days: BTreeSet<NaiveDate>,
+    offset: i32,
+    transitions: Vec<Transition>,
+}
+
+#[derive(Debug, Clone)]
+struct Transition {
+    utctime: NaiveDateTime,
+    oldoffset: i32,
+    newoffset: i32,
+}
+
+impl WorkingHours {
+    /// Maximum number of days searched when calculating a deadline.
+    const MAX_DAYS: i64 = 3660;
+
+    /// Start building new opening hours. The location is closed every day until opening hours
+    /// are added.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the opening hours for a day of the week.
+    pub fn with_day(mut self, weekday: Weekday, open: NaiveTime, close: NaiveTime) -> Self {
+        self.days[weekday.num_days_from_monday() as usize] = Some((open, close));
+
+        self
+    }
+
+    /// Set the opening hours for Monday through Friday.
+    pub fn with_weekdays(self, open: NaiveTime, close: NaiveTime) -> Self {
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+        .iter()
+        .fold(self, |hours, day| hours.with_day(*day, open, close))
+    }
+
+    /// Set the opening hours for half day holidays. Defaults to the regular opening hours,
+    /// closing at noon.
+    pub fn set_half_day_hours(mut self, open: NaiveTime, close: NaiveTime) -> Self {
+        self.half_day = Some((open, close));
+
+        self
+    }
+
+    /// Add holidays during which the location is closed.
+    ///
+    /// Half day holidays only shorten the opening hours. Every other holiday closes the location,
+    /// so the holidays should be requested with the holiday types that apply to the location.
+    pub fn with_holidays<'a>(mut self, holidays: impl IntoIterator<Item = &'a Holiday>) -> Self {
+        for holiday in holidays {
+            let date = match naive_date(&holiday.date.datetime) {
+                Some(date) => date,
+                None => continue,
+            };
+
+            if holiday.is_type(HolidayType::HalfDay) {
+                self.half_days.insert(date);
+            } else {
+                self.closed.insert(date);
+            }
+        }
+
+        self
+    }
+
+    /// Add a date on which the location is closed.
+    pub fn with_closed_date(mut self, date: &DateTime) -> Self {
+        if let Some(date) = naive_date(date) {
+            self.closed.insert(date);
+        }
+
+        self
+    }
+
+    /// Add a date on which the location uses the half day opening hours.
+    pub fn with_half_day(mut self, date: &DateTime) -> Self {
+        if let Some(date) = naive_date(date) {
+            self.half_days.insert(date);
+        }
+
+        self
+    }
+
+    /// Set the time zone of the location. Used when no time changes are known.
+    pub fn set_timezone(mut self, timezone: &TimeZone) -> Self {
+        self.offset = timezone.zonetotaloffset;
+
+        self
+    }
+
+    /// Add time changes (daylight savings time) for the location.
+    pub fn with_timechanges<'a>(
+        mut self,
+        timechanges: impl IntoIterator<Item = &'a TimeChange>,
+    ) -> Self {
+        for change in timechanges {
+            let utctime = naive_datetime(&DateTime::from(&change.utctime));
+            let oldlocaltime = naive_datetime(&DateTime::from(&change.oldlocaltime));
+
+            if let (Some(utctime), Some(oldlocaltime)) = (utctime, oldlocaltime) {
+                self.transitions.push(Transition {
+                    utctime,
+                    oldoffset: (oldlocaltime - utctime).num_seconds() as i32,
+                    newoffset: change.newoffset,
+                });
+            }
+        }
+
+        self.transitions.sort_by_key(|t| t.utctime);
+
+        self
+    }
+
+    /// Set the time zone and time changes from a location returned by the time services.
+    pub fn set_location(mut self, location: &Location) -> Self {
+        if let Some(timezone) = location.time.as_ref().and_then(|t| t.timezone.as_ref()) {
+            self = self.set_timezone(timezone);
+        }
+
+        if let Some(ref timechanges) = location.timechanges {
+            self = self.with_timechanges(timechanges);
+        }
+
+        self
+    }
+
+    /// Opening hours in local time for a date, if the location is open.
+    pub fn hours_on(&self, date: NaiveDate) -> Option<(NaiveTime, NaiveTime)> {
+        if self.closed.contains(&date) {
+            return None;
+        }
+
+        let (open, close) = self.days[date.weekday().num_days_from_monday() as usize]?;
+
+        if !self.half_days.contains(&date) {
+            return Some((open, close));
+        }
+
+        match self.half_day {
+            Some(hours) => Some(hours),
+            None => {
+                let noon = NaiveTime::from_hms_opt(12, 0, 0)?;
+
+                if open < noon {
+                    Some((open, close.min(noon)))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Business hours elapsed between two points in time.
+    pub fn business_duration(&self, start: &Instant, end: &Instant) -> Duration {
+        let mut total = Duration::zero();
+
+        if end <= start {
+            return total;
+        }
+
+        let mut date = self.local(start).date();
+        let last = self.local(end).date();
+
+        while date <= last {
+            if let Some((open, close)) = self.window(date) {
+                let open = open.max(*start);
+                let close = close.min(*end);
+
+                if close > open {
+                    total += close - open;
+                }
+            }
+
+            date = match date.succ_opt() {
+                Some(date) => date,
+                None => break,
+            };
+        }
+
+        total
+    }
+
+    /// Point in time at which the given amount of business hours has elapsed after the start.
+    ///
+    /// Returns `None` if the location is not open within the next ten years.
+    pub fn deadline(&self, start: &Instant, duration: Duration) -> Option<Instant> {
+        let mut remaining = duration;
+        let mut date = self.local(start).date();
+
+        if remaining <= Duration::zero() {
+            return Some(*start);
+        }
+
+        for _ in 0..Self::MAX_DAYS {
+            if let Some((open, close)) = self.window(date) {
+                let open = open.max(*start);
+
+                if close > open {
+                    if close - open >= remaining {
+                        return Some(open + remaining);
+                    }
+
+                    remaining -= close - open;
+                }
+            }
+
+            date = date.succ_opt()?;
+        }
+
+        None
+    }
+
+    /// Opening hours for a local date, as points in time.
+    fn window(&self, date: NaiveDate) -> Option<(Instant, Instant)> {
+        let (open, close) = self.hours_on(date)?;
+
+        Some((
+            self.utc(date.and_time(open)),
+            self.utc(date.and_time(close)),
+        ))
+    }
+
+    fn offset_at(&self, utc: NaiveDateTime) -> i32 {
+        match self.transitions.iter().rev().find(|t| t.utctime <= utc) {
+            Some(transition) => transition.newoffset,
+            None => self
+                .transitions
+                .first()
+                .map(|t| t.oldoffset)
+                .unwrap_or(self.offset),
+        }
+    }
+
+    fn local(&self, instant: &Instant) -> NaiveDateTime {
+        let utc = instant.naive_utc();
+
+        utc + Duration::seconds(self.offset_at(utc).into())
+    }
+
+    fn utc(&self, local: NaiveDateTime) -> Instant {
+        let guess = local - Duration::seconds(self.offset_at(local).into());
+        let utc = local - Duration::seconds(self.offset_at(guess).into());
+
+        Instant::from_naive_utc_and_offset(utc, Utc)
+    }
+}
@@ -1,10 +1,20 @@
-use super::Service;
-use crate::{ApiError, Error, ServiceClient};
+use super::{Service, Validate};
+use crate::{models::time::DateTime, ApiError, Error, ServiceClient};
 use maybe_async::maybe_async;
+use std::convert::TryFrom;
+use url::Url;
 
+mod calendar;
+mod index;
 mod request;
 mod response;
 
+/// Holiday calendar across offices.
+pub use calendar::{HolidayCalendar, Office};
+
+/// Holiday lookups by date, country and type.
+pub use index::{HolidayFilter, HolidayIndex};
+
 /// Holidays API request.
 pub use request::HolidaysRequest;
 
@@ -23,10 +33,127 @@ impl Service for HolidaysService {
 impl ServiceClient {
     #[maybe_async]
     /// The *Holidays* service can be used to retrieve a list of holidays for a country.
+    ///
+    /// Requests with a date range spanning several years are split into one request per year,
+    /// and the holidays are combined in date order without duplicates.
     pub async fn get_holidays(
         &self,
         request: &HolidaysRequest,
     ) -> Result<Result<HolidaysResponse, ApiError>, Error> {
-        self.call::<HolidaysService>(request).await
+        let (start, end) = match request.range() {
+            Some(range) => range,
+            None => return self.call::<HolidaysService>(request).await,
+        };
+
+        request.validate()?;
+
+        let mut response = HolidaysResponse { holidays: vec![] };
+
+        for year in request.split_years() {
+            match self.call::<HolidaysService>(&year).await? {
+                Ok(r) => response.merge(r),
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+
+        response.restrict(&start, &end);
+
+        Ok(Ok(response))
+    }
+
+    /// Signed URL for a request to the *Holidays* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_holidays_url(&self, request: &HolidaysRequest) -> Result<Url, Error> {
+        self.sign_url::<HolidaysService>(request)
+    }
+
+    #[cfg(feature = "__async")]
+    /// Stream holidays for every country in the request over a span of years.
+    ///
+    /// Holidays are fetched lazily one country and year at a time, ignoring the year set on the
+    /// request. The stream ends after the first error.
+    pub fn stream_holidays(
+        &self,
+        request: &HolidaysRequest,
+        years: std::ops::RangeInclusive<u16>,
+    ) -> impl futures::Stream<Item = Result<Result<crate::models::holidays::Holiday, ApiError>, Error>>
+           + '_ {
+        let pages = request.split(years);
+
+        crate::paging::paginate(pages, move |page: HolidaysRequest| async move {
+            let response = self.get_holidays(&page).await?;
+
+            Ok(response.map(|response| {
+                response
+                    .holidays
+                    .into_iter()
+                    .map(|holiday| (holiday.uid.clone(), holiday))
+                    .collect()
+            }))
+        })
+    }
+
+    #[maybe_async]
+    /// Load holidays for a year into a holiday calendar, unless the year is already loaded.
+    pub async fn load_holiday_calendar(
+        &self,
+        calendar: &mut HolidayCalendar,
+        year: u16,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if calendar.is_loaded(year) {
+            return Ok(Ok(()));
+        }
+
+        let request = match calendar.request(year) {
+            Some(request) => request,
+            None => return Ok(Ok(())),
+        };
+
+        match self.get_holidays(&request).await? {
+            Ok(response) => calendar.insert(year, response),
+            Err(e) => return Ok(Err(e)),
+        }
+
+        Ok(Ok(()))
+    }
+
+    #[maybe_async]
+    /// List the offices of a holiday calendar which are closed on a given date, loading the
+    /// holidays for the year of the date if needed.
+    pub async fn get_closed_offices(
+        &self,
+        calendar: &mut HolidayCalendar,
+        date: &DateTime,
+    ) -> Result<Result<Vec<Office>, ApiError>, Error> {
+        if let Ok(year) = u16::try_from(date.year) {
+            if let Err(e) = self.load_holiday_calendar(calendar, year).await? {
+                return Ok(Err(e));
+            }
+        }
+
+        Ok(Ok(calendar
+            .closed_offices(date)
+            .into_iter()
+            .cloned()
+            .collect()))
+    }
+
+    #[maybe_async]
+    /// Find the first date after the given date on which every office of a holiday calendar is
+    /// open, loading the holidays for the required years if needed.
+    pub async fn get_next_common_working_day(
+        &self,
+        calendar: &mut HolidayCalendar,
+        date: &DateTime,
+    ) -> Result<Result<Option<DateTime>, ApiError>, Error> {
+        if let Ok(year) = u16::try_from(date.year) {
+            for year in year..=year.saturating_add(1) {
+                if let Err(e) = self.load_holiday_calendar(calendar, year).await? {
+                    return Ok(Err(e));
+                }
+            }
+        }
+
+        Ok(Ok(calendar.next_common_working_day(date)))
     }
 }
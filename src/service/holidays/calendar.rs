@@ -0,0 +1,247 @@
+use super::{HolidaysRequest, HolidaysResponse};
+use crate::convert::{from_naive_date, naive_date};
+use crate::models::{
+    holidays::{Holiday, HolidayType},
+    time::DateTime,
+};
+use chrono::{Datelike, Weekday};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An office location, identified by a country and optionally a state/subdivision.
+pub struct Office {
+    /// The ISO 3166-1-alpha-2 country code.
+    ///
+    /// Example: us
+    pub country: String,
+
+    /// An ISO 3166-2 state code or the abbreviation of the state/subdivision.
+    ///
+    /// Example: us-ca
+    pub state: Option<String>,
+
+    /// Days of the week on which the office is closed. Defaults to Saturday and Sunday.
+    pub weekend: Vec<Weekday>,
+}
+
+impl Office {
+    /// Create an office for a whole country.
+    pub fn new(country: impl Into<String>) -> Self {
+        Self {
+            country: country.into().to_lowercase(),
+            state: None,
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+        }
+    }
+
+    /// Restrict the office to a state/subdivision of its country.
+    pub fn with_state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+
+        self
+    }
+
+    /// Set the days of the week on which the office is closed, e.g. Friday and Saturday.
+    pub fn with_weekend(mut self, weekend: impl IntoIterator<Item = Weekday>) -> Self {
+        self.weekend = weekend.into_iter().collect();
+
+        self
+    }
+
+    /// Whether a date falls on the office's weekend.
+    pub fn is_weekend(&self, date: &DateTime) -> bool {
+        naive_date(date)
+            .map(|d| self.weekend.contains(&d.weekday()))
+            .unwrap_or(false)
+    }
+
+    /// Whether a holiday of the office's country applies to this office.
+    ///
+    /// Holidays without states affect the whole country, while holidays with states only affect
+    /// offices located in one of them.
+    pub fn observes(&self, holiday: &Holiday) -> bool {
+        match (&holiday.states, &self.state) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(states), Some(state)) => states
+                .iter()
+                .any(|s| s.iso.eq_ignore_ascii_case(state) || s.abbrev.eq_ignore_ascii_case(state)),
+        }
+    }
+}
+
+#[derive(Default)]
+/// Holiday calendar for a set of offices across countries and subdivisions.
+///
+/// Holidays are grouped by country and deduplicated by their `uid`. Offices are also closed on
+/// their weekend, Saturday and Sunday unless set with [`Office::with_weekend`].
+///
+/// Years are loaded on demand by the client, see [`ServiceClient::load_holiday_calendar`].
+///
+/// Example:
+/// ```no_run
+/// use libtad_rs::{
+///     ServiceClient,
+///     service::holidays::{HolidayCalendar, Office},
+///     models::time::DateTime,
+/// };
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into());
+/// let mut calendar = HolidayCalendar::new()
+///     .with_office(Office::new("no"))
+///     .with_office(Office::new("us").with_state("us-ca"));
+///
+/// let closed = client.get_closed_offices(&mut calendar, &DateTime::from("2022-05-17"));
+/// ```
+///
+/// [`ServiceClient::load_holiday_calendar`]: crate::ServiceClient::load_holiday_calendar
+pub struct HolidayCalendar {
+    offices: Vec<Office>,
+    types: Vec<HolidayType>,
+    years: BTreeSet<u16>,
+    uids: HashSet<String>,
+    holidays: BTreeMap<String, Vec<Holiday>>,
+}
+
+impl HolidayCalendar {
+    const DEFAULT_TYPES: [HolidayType; 3] = [
+        HolidayType::Federal,
+        HolidayType::FederalLocal,
+        HolidayType::Local,
+    ];
+
+    /// Start building a new calendar.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add an office to the calendar. If the office is in a new country, loaded years are marked
+    /// as not loaded, so the holidays of the country are fetched with the next request.
+    pub fn with_office(mut self, office: Office) -> Self {
+        if !self.offices.iter().any(|o| o.country == office.country) {
+            self.years.clear();
+        }
+
+        self.offices.push(office);
+
+        self
+    }
+
+    /// Add a holiday type which closes the offices. Defaults to federal, common local and local
+    /// holidays.
+    pub fn with_type(mut self, holiday_type: HolidayType) -> Self {
+        self.types.push(holiday_type);
+
+        self
+    }
+
+    /// Offices in the calendar.
+    pub fn offices(&self) -> &[Office] {
+        &self.offices
+    }
+
+    /// Whether holidays for a year have been loaded.
+    pub fn is_loaded(&self, year: u16) -> bool {
+        self.years.contains(&year)
+    }
+
+    /// Build the request fetching holidays for every office country in a year.
+    ///
+    /// Returns `None` if the calendar has no offices.
+    pub fn request(&self, year: u16) -> Option<HolidaysRequest> {
+        let countries: BTreeSet<_> = self.offices.iter().map(|o| o.country.as_str()).collect();
+        let mut countries = countries.into_iter();
+
+        let mut request = HolidaysRequest::new()
+            .set_year(year)
+            .with_country(countries.next()?);
+
+        for country in countries {
+            request = request.with_country(country);
+        }
+
+        let types = if self.types.is_empty() {
+            &Self::DEFAULT_TYPES[..]
+        } else {
+            &self.types[..]
+        };
+
+        for holiday_type in types {
+            request = request.with_type(*holiday_type);
+        }
+
+        Some(request)
+    }
+
+    /// Merge holidays returned for a year into the calendar.
+    ///
+    /// Holidays without a country are ignored, and holidays already present are skipped.
+    pub fn insert(&mut self, year: u16, response: HolidaysResponse) {
+        self.years.insert(year);
+
+        for holiday in response.holidays {
+            let country = match holiday.country {
+                Some(ref country) => country.id.to_lowercase(),
+                None => continue,
+            };
+
+            if self.uids.insert(holiday.uid.clone()) {
+                self.holidays.entry(country).or_default().push(holiday);
+            }
+        }
+    }
+
+    /// All loaded holidays for a country.
+    pub fn holidays(&self, country: &str) -> &[Holiday] {
+        self.holidays
+            .get(&country.to_lowercase())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Holidays observed by an office on a given date.
+    pub fn holidays_on(&self, office: &Office, date: &DateTime) -> Vec<&Holiday> {
+        self.holidays(&office.country)
+            .iter()
+            .filter(|h| same_date(&h.date.datetime, date) && office.observes(h))
+            .collect()
+    }
+
+    /// Whether an office is closed on a given date, either because of its weekend or a holiday.
+    pub fn is_closed(&self, office: &Office, date: &DateTime) -> bool {
+        office.is_weekend(date) || !self.holidays_on(office, date).is_empty()
+    }
+
+    /// Offices which are closed on a given date.
+    pub fn closed_offices(&self, date: &DateTime) -> Vec<&Office> {
+        self.offices
+            .iter()
+            .filter(|o| self.is_closed(o, date))
+            .collect()
+    }
+
+    /// First date after the given date on which every office is open, searching only loaded
+    /// years.
+    pub fn next_common_working_day(&self, date: &DateTime) -> Option<DateTime> {
+        let mut day = naive_date(date)?.succ_opt()?;
+
+        loop {
+            if !self.is_loaded(u16::try_from(day.year()).ok()?) {
+                return None;
+            }
+
+            let candidate = from_naive_date(day);
+
+            if self.closed_offices(&candidate).is_empty() {
+                return Some(candidate);
+            }
+
+            day = day.succ_opt()?;
+        }
+    }
+}
+
+fn same_date(a: &DateTime, b: &DateTime) -> bool {
+    a.year == b.year && a.month == b.month && a.day == b.day
+}
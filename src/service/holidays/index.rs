@@ -0,0 +1,251 @@
+use super::HolidaysResponse;
+use crate::convert::naive_date;
+use crate::models::holidays::{Holiday, HolidayType};
+use chrono::{Datelike, Duration, FixedOffset, NaiveDate};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::iter::FromIterator;
+
+type Dates = BTreeMap<NaiveDate, Vec<usize>>;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Selection of holidays by country, state/subdivision and type.
+pub struct HolidayFilter {
+    country: Option<String>,
+    state: Option<String>,
+    types: Vec<HolidayType>,
+}
+
+impl HolidayFilter {
+    /// Start building a filter matching every holiday.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only match holidays of a country, given by its ISO 3166-1-alpha-2 code.
+    pub fn set_country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into().to_lowercase());
+
+        self
+    }
+
+    /// Only match holidays observed in a state/subdivision, given by its ISO 3166-2 code or
+    /// abbreviation. Holidays affecting the whole country are observed in every state.
+    pub fn set_state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+
+        self
+    }
+
+    /// Add a holiday type to match. Holidays of any of the added types match, and combined types
+    /// like `Religious` match the types they combine. Defaults to every type.
+    pub fn with_type(mut self, holiday_type: HolidayType) -> Self {
+        self.types.push(holiday_type);
+
+        self
+    }
+
+    /// Whether a holiday matches the filter.
+    pub fn matches(&self, holiday: &Holiday) -> bool {
+        let country = match (&self.country, &holiday.country) {
+            (None, _) => true,
+            (Some(country), Some(c)) => c.id.eq_ignore_ascii_case(country),
+            (Some(_), None) => false,
+        };
+
+        let state = match (&holiday.states, &self.state) {
+            (None, _) | (Some(_), None) => true,
+            (Some(states), Some(state)) => states
+                .iter()
+                .any(|s| s.iso.eq_ignore_ascii_case(state) || s.abbrev.eq_ignore_ascii_case(state)),
+        };
+
+        let types = self.types.is_empty() || self.types.iter().any(|t| holiday.is_type(*t));
+
+        country && state && types
+    }
+}
+
+#[derive(Default)]
+/// Index of holidays by date and country, for repeated lookups without API calls.
+///
+/// Holidays are deduplicated by their `uid`. Holidays with an exact time, like equinoxes and
+/// solstices, are dated in the local time returned by the API, or in a given offset with
+/// [`HolidayIndex::on_in`].
+///
+/// Example:
+/// ```
+/// use libtad_rs::{
+///     ServiceClient,
+///     service::holidays::{HolidayFilter, HolidayIndex, HolidaysRequest},
+///     models::holidays::HolidayType,
+/// };
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into());
+/// let request = HolidaysRequest::new()
+///     .with_country("no")
+///     .set_year(2022);
+///
+/// if let Ok(Ok(response)) = client.get_holidays(&request) {
+///     let index = HolidayIndex::from(response);
+///     let date = chrono::NaiveDate::from_ymd_opt(2022, 5, 17).unwrap();
+///     let federal = HolidayFilter::new()
+///         .set_country("no")
+///         .with_type(HolidayType::Federal);
+///
+///     let is_holiday = index.is_holiday(date, &federal);
+///     let next = index.next(date, &federal);
+/// }
+/// ```
+pub struct HolidayIndex {
+    holidays: Vec<Holiday>,
+    uids: HashSet<String>,
+    dates: Dates,
+    countries: HashMap<String, Dates>,
+}
+
+impl HolidayIndex {
+    /// Create an empty index.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add the holidays of a response, skipping holidays already present. Holidays without a
+    /// valid date are ignored.
+    pub fn insert(&mut self, response: HolidaysResponse) {
+        for holiday in response.holidays {
+            let date = match naive_date(&holiday.date.datetime) {
+                Some(date) => date,
+                None => continue,
+            };
+
+            if !self.uids.insert(holiday.uid.clone()) {
+                continue;
+            }
+
+            let i = self.holidays.len();
+
+            self.dates.entry(date).or_default().push(i);
+
+            if let Some(ref country) = holiday.country {
+                self.countries
+                    .entry(country.id.to_lowercase())
+                    .or_default()
+                    .entry(date)
+                    .or_default()
+                    .push(i);
+            }
+
+            self.holidays.push(holiday);
+        }
+    }
+
+    /// Number of holidays in the index.
+    pub fn len(&self) -> usize {
+        self.holidays.len()
+    }
+
+    /// Whether the index has no holidays.
+    pub fn is_empty(&self) -> bool {
+        self.holidays.is_empty()
+    }
+
+    /// Holidays matching a filter on a date.
+    pub fn on(&self, date: NaiveDate, filter: &HolidayFilter) -> Vec<&Holiday> {
+        self.between(date, date, filter)
+    }
+
+    /// Whether any holiday matching a filter falls on a date.
+    pub fn is_holiday(&self, date: NaiveDate, filter: &HolidayFilter) -> bool {
+        !self.on(date, filter).is_empty()
+    }
+
+    /// Holidays matching a filter on a date in a UTC offset. Holidays with an exact time are
+    /// dated by their time in the offset, other holidays by their date.
+    pub fn on_in(
+        &self,
+        date: NaiveDate,
+        offset: FixedOffset,
+        filter: &HolidayFilter,
+    ) -> Vec<&Holiday> {
+        let from = date - Duration::days(1);
+        let to = date + Duration::days(1);
+
+        self.range(from, to, filter)
+            .filter(|(local, holiday)| match holiday.date.local() {
+                Some(time) => time.with_timezone(&offset).date_naive() == date,
+                None => *local == date,
+            })
+            .map(|(_, holiday)| holiday)
+            .collect()
+    }
+
+    /// Holidays matching a filter within an inclusive date range, in date order.
+    pub fn between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        filter: &HolidayFilter,
+    ) -> Vec<&Holiday> {
+        self.range(start, end, filter)
+            .map(|(_, holiday)| holiday)
+            .collect()
+    }
+
+    /// Holidays matching a filter in the week (Monday to Sunday) of a date.
+    pub fn in_week(&self, date: NaiveDate, filter: &HolidayFilter) -> Vec<&Holiday> {
+        let monday = date - Duration::days(date.weekday().num_days_from_monday().into());
+
+        self.between(monday, monday + Duration::days(6), filter)
+    }
+
+    /// First holiday matching a filter after a date.
+    pub fn next(&self, after: NaiveDate, filter: &HolidayFilter) -> Option<&Holiday> {
+        let from = after.succ_opt()?;
+
+        self.range(from, NaiveDate::MAX, filter)
+            .map(|(_, holiday)| holiday)
+            .next()
+    }
+
+    /// Matching holidays within an inclusive date range with their dates, in date order.
+    fn range<'a: 'f, 'f>(
+        &'a self,
+        start: NaiveDate,
+        end: NaiveDate,
+        filter: &'f HolidayFilter,
+    ) -> impl Iterator<Item = (NaiveDate, &'a Holiday)> + 'f {
+        let dates = match filter.country {
+            Some(ref country) => self.countries.get(country),
+            None => Some(&self.dates),
+        };
+
+        dates
+            .filter(|_| start <= end)
+            .into_iter()
+            .flat_map(move |dates| dates.range(start..=end))
+            .flat_map(move |(date, holidays)| holidays.iter().map(move |i| (*date, i)))
+            .map(move |(date, i)| (date, &self.holidays[*i]))
+            .filter(move |(_, holiday)| filter.matches(holiday))
+    }
+}
+
+impl From<HolidaysResponse> for HolidayIndex {
+    fn from(response: HolidaysResponse) -> Self {
+        let mut index = Self::new();
+        index.insert(response);
+
+        index
+    }
+}
+
+impl FromIterator<HolidaysResponse> for HolidayIndex {
+    fn from_iter<I: IntoIterator<Item = HolidaysResponse>>(responses: I) -> Self {
+        let mut index = Self::new();
+
+        for response in responses {
+            index.insert(response);
+        }
+
+        index
+    }
+}
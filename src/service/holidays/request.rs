@@ -1,12 +1,17 @@
-use crate::service::{ProvidedArgument, RequiredArgument};
+use crate::convert::naive_date;
+use crate::models::time::DateTime;
+use crate::service::{ProvidedArgument, RequiredArgument, Validate};
+use crate::ValidationError;
 use libtad_models::holidays::HolidayType;
 use serde::Serialize;
+use std::convert::TryFrom;
 
 macro_rules! return_type {
     ($self:ident) => {
         HolidaysRequest {
             country: $self.country,
             year: $self.year,
+            range: $self.range,
             lang: $self.lang,
             types: $self.types,
             tz: $self.tz,
@@ -17,7 +22,7 @@ macro_rules! return_type {
     };
 }
 
-#[derive(Default, Serialize)]
+#[derive(Default, Clone, Serialize)]
 /// Holidays API request.
 ///
 /// Request is validated at compile time when supplied to the client.
@@ -40,6 +45,8 @@ macro_rules! return_type {
 pub struct HolidaysRequest<A = ProvidedArgument, B = ProvidedArgument> {
     country: Vec<String>,
     year: u16,
+    #[serde(skip)]
+    range: Option<(DateTime, DateTime)>,
     lang: Option<Vec<String>>,
     types: Option<Vec<HolidayType>>,
     tz: Option<u8>,
@@ -53,6 +60,45 @@ impl HolidaysRequest {
     pub fn new() -> HolidaysRequest<RequiredArgument, RequiredArgument> {
         Default::default()
     }
+
+    #[cfg(feature = "__async")]
+    /// Split the request into one request per country and year in `years`.
+    pub(crate) fn split(&self, years: std::ops::RangeInclusive<u16>) -> Vec<Self> {
+        years
+            .flat_map(|year| {
+                self.country.iter().map(move |country| {
+                    let mut request = self.clone();
+                    request.country = vec![country.clone()];
+                    request.year = year;
+                    request.range = None;
+                    request
+                })
+            })
+            .collect()
+    }
+
+    /// Date range set on the request, if any.
+    pub(crate) fn range(&self) -> Option<(DateTime, DateTime)> {
+        self.range
+    }
+
+    /// Split a request with a date range into one request per year of the range.
+    pub(crate) fn split_years(&self) -> Vec<Self> {
+        let (start, end) = match self.range {
+            Some(range) => range,
+            None => return vec![self.clone()],
+        };
+
+        (start.year..=end.year)
+            .filter_map(|year| u16::try_from(year).ok())
+            .map(|year| {
+                let mut request = self.clone();
+                request.year = year;
+                request.range = None;
+                request
+            })
+            .collect()
+    }
 }
 
 impl<A, B> HolidaysRequest<A, B> {
@@ -69,6 +115,23 @@ impl<A, B> HolidaysRequest<A, B> {
     /// Set the request year for the request.
     pub fn set_year(mut self, year: u16) -> HolidaysRequest<A, ProvidedArgument> {
         self.year = year;
+        self.range = None;
+
+        return_type!(self)
+    }
+
+    /// Set an inclusive date range for the request, replacing the year. The range may span
+    /// several years.
+    ///
+    /// The client requests one year at a time and returns the holidays within the range in date
+    /// order. Signed URLs only cover the first year of the range.
+    pub fn set_range(
+        mut self,
+        start: DateTime,
+        end: DateTime,
+    ) -> HolidaysRequest<A, ProvidedArgument> {
+        self.year = u16::try_from(start.year).unwrap_or_default();
+        self.range = Some((start, end));
 
         return_type!(self)
     }
@@ -78,7 +141,7 @@ impl<A, B> HolidaysRequest<A, B> {
         if let Some(ref mut langs) = self.lang {
             langs.push(lang.into());
         } else {
-            self.lang.insert(vec![lang.into()]);
+            self.lang = Some(vec![lang.into()]);
         }
 
         self
@@ -89,7 +152,7 @@ impl<A, B> HolidaysRequest<A, B> {
         if let Some(ref mut types) = self.types {
             types.push(holiday_type);
         } else {
-            self.types.insert(vec![holiday_type]);
+            self.types = Some(vec![holiday_type]);
         }
 
         self
@@ -97,15 +160,53 @@ impl<A, B> HolidaysRequest<A, B> {
 
     /// Toggle whether to request time zone information.
     pub fn set_tz(mut self, enable: bool) -> Self {
-        self.tz.insert(enable.into());
+        self.tz = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to request verbose time.
     pub fn set_verbose_time(mut self, enable: bool) -> Self {
-        self.verbosetime.insert(enable.into());
+        self.verbosetime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for HolidaysRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let (start, end) = match self.range {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+
+        let invalid = |date: &DateTime| {
+            ValidationError::new(
+                "range",
+                format!(
+                    "{:04}-{:02}-{:02} is not a valid date",
+                    date.year, date.month, date.day
+                ),
+            )
+        };
+
+        let first = naive_date(&start).ok_or_else(|| invalid(&start))?;
+        let last = naive_date(&end).ok_or_else(|| invalid(&end))?;
+
+        if last < first {
+            return Err(ValidationError::new(
+                "range",
+                format!("range ends on {} before it starts on {}", last, first),
+            ));
+        }
+
+        if u16::try_from(start.year).is_err() || u16::try_from(end.year).is_err() {
+            return Err(ValidationError::new(
+                "range",
+                format!("years {} to {} are out of range", start.year, end.year),
+            ));
+        }
+
+        Ok(())
+    }
+}
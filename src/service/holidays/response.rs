@@ -1,5 +1,8 @@
+use crate::convert::naive_date;
+use crate::models::time::DateTime;
 use libtad_models::holidays::Holiday;
 use serde::Deserialize;
+use std::collections::HashSet;
 
 #[derive(Debug, Deserialize)]
 /// Holidays API response.
@@ -7,3 +10,31 @@ pub struct HolidaysResponse {
     /// List of returned holidays.
     pub holidays: Vec<Holiday>,
 }
+
+impl HolidaysResponse {
+    /// Add the holidays of another response, skipping holidays already present by `uid`.
+    pub(crate) fn merge(&mut self, other: HolidaysResponse) {
+        let mut uids: HashSet<_> = self.holidays.iter().map(|h| h.uid.clone()).collect();
+
+        self.holidays.extend(
+            other
+                .holidays
+                .into_iter()
+                .filter(|holiday| uids.insert(holiday.uid.clone())),
+        );
+    }
+
+    /// Keep only holidays within an inclusive date range, in date order.
+    pub(crate) fn restrict(&mut self, start: &DateTime, end: &DateTime) {
+        let (start, end) = (naive_date(start), naive_date(end));
+
+        self.holidays.retain(|holiday| {
+            let date = naive_date(&holiday.date.datetime);
+
+            date >= start && (end.is_none() || date <= end)
+        });
+
+        self.holidays
+            .sort_by_key(|holiday| naive_date(&holiday.date.datetime));
+    }
+}
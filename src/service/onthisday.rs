@@ -1,6 +1,7 @@
 use super::Service;
 use crate::{ApiError, Error, ServiceClient};
 use maybe_async::maybe_async;
+use url::Url;
 
 mod request;
 mod response;
@@ -30,4 +31,10 @@ impl ServiceClient {
     ) -> Result<Result<OnThisDayResponse, ApiError>, Error> {
         self.call::<OnThisDayService>(request).await
     }
+
+    /// Signed URL for a request to the *On This Day* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_events_on_this_day_url(&self, request: &OnThisDayRequest) -> Result<Url, Error> {
+        self.sign_url::<OnThisDayService>(request)
+    }
 }
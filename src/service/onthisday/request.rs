@@ -1,3 +1,6 @@
+use crate::service::Validate;
+use crate::ValidationError;
+use chrono::NaiveDate;
 use libtad_models::onthisday::EventType;
 use serde::Serialize;
 
@@ -38,14 +41,14 @@ impl OnThisDayRequest {
 
     /// Set month for the request.
     pub fn set_month(mut self, month: u8) -> Self {
-        self.month.insert(month);
+        self.month = Some(month);
 
         self
     }
 
     /// Set day for the request.
     pub fn set_day(mut self, day: u8) -> Self {
-        self.day.insert(day);
+        self.day = Some(day);
 
         self
     }
@@ -55,7 +58,7 @@ impl OnThisDayRequest {
         if let Some(ref mut langs) = self.lang {
             langs.push(lang.into());
         } else {
-            self.lang.insert(vec![lang.into()]);
+            self.lang = Some(vec![lang.into()]);
         }
 
         self
@@ -66,9 +69,40 @@ impl OnThisDayRequest {
         if let Some(ref mut types) = self.types {
             types.push(event_type);
         } else {
-            self.types.insert(vec![event_type]);
+            self.types = Some(vec![event_type]);
         }
 
         self
     }
 }
+
+impl Validate for OnThisDayRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let month = match (self.month, self.day) {
+            (None, Some(_)) => {
+                return Err(ValidationError::new("day", "day is set without a month"))
+            }
+            (None, None) => return Ok(()),
+            (Some(month), _) => month,
+        };
+
+        if !(1..=12).contains(&month) {
+            return Err(ValidationError::new(
+                "month",
+                format!("{} is not a valid month", month),
+            ));
+        }
+
+        if let Some(day) = self.day {
+            // Leap year, so that February 29th is accepted.
+            if NaiveDate::from_ymd_opt(2000, month.into(), day.into()).is_none() {
+                return Err(ValidationError::new(
+                    "day",
+                    format!("{}/{} is not a valid date", month, day),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,25 @@
+use super::time::TimeserviceRequest;
 use super::Service;
+use crate::models::{places::PlaceId, Distance};
 use crate::{ApiError, Error, ServiceClient};
 use maybe_async::maybe_async;
+use url::Url;
 
+mod catalog;
+mod nearest;
 mod request;
+mod resolver;
 mod response;
 
+/// Local catalog of all places.
+pub use catalog::{PlaceCatalog, PlaceCatalogDiff};
+
+/// Nearest place to a coordinate.
+pub use nearest::NearestPlace;
+
+/// Place name resolution.
+pub use resolver::{PlaceQuery, PlaceResolver, Resolution};
+
 /// Places API request.
 pub use request::PlacesRequest;
 
@@ -29,4 +44,76 @@ impl ServiceClient {
     ) -> Result<Result<PlacesResponse, ApiError>, Error> {
         self.call::<PlacesService>(request).await
     }
+
+    /// Signed URL for a request to the *Places* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_places_url(&self, request: &PlacesRequest) -> Result<Url, Error> {
+        self.sign_url::<PlacesService>(request)
+    }
+
+    #[maybe_async]
+    /// Resolve a place name to the best matching place, using the resolver's cache if the query
+    /// has been resolved before.
+    pub async fn resolve_place(
+        &self,
+        resolver: &mut PlaceResolver,
+        query: &PlaceQuery,
+    ) -> Result<Result<Option<Resolution>, ApiError>, Error> {
+        if let Some(resolution) = resolver.cached(query) {
+            return Ok(Ok(resolution.clone()));
+        }
+
+        match self.get_places(&resolver.request(query)).await? {
+            Ok(response) => resolver.insert(query, response),
+            Err(e) => return Ok(Err(e)),
+        }
+
+        Ok(Ok(resolver.cached(query).cloned().flatten()))
+    }
+
+    #[maybe_async]
+    /// Find the place nearest to a coordinate, optionally within a search radius.
+    ///
+    /// Example:
+    /// ```
+    /// use libtad_rs::ServiceClient;
+    ///
+    /// let client = ServiceClient::new("access_key".into(), "secret_key".into());
+    /// let nearest = client.nearest_place(59.914, 10.752, None);
+    /// ```
+    pub async fn nearest_place(
+        &self,
+        latitude: f32,
+        longitude: f32,
+        radius: Option<Distance>,
+    ) -> Result<Result<Option<NearestPlace>, ApiError>, Error> {
+        let placeid = PlaceId::Coordinates {
+            latitude,
+            longitude,
+        };
+
+        let mut request = TimeserviceRequest::new()
+            .set_placeid(placeid)
+            .set_geo(true)
+            .set_time(false);
+
+        if let Some(radius) = radius {
+            request = request.set_radius(radius.km().round() as i32);
+        }
+
+        Ok(self.get_current_time(&request).await?.map(|response| {
+            let location = response.locations.into_iter().next()?;
+
+            Some(NearestPlace::new(location, latitude, longitude))
+        }))
+    }
+
+    #[maybe_async]
+    /// Download every supported place into a catalog for local search.
+    pub async fn load_place_catalog(&self) -> Result<Result<PlaceCatalog, ApiError>, Error> {
+        Ok(self
+            .get_places(&PlaceCatalog::request())
+            .await?
+            .map(PlaceCatalog::from))
+    }
 }
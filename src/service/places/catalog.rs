@@ -0,0 +1,294 @@
+use super::resolver::{normalize, similarity};
+use super::{PlacesRequest, PlacesResponse};
+use crate::models::{places::Place, Distance};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::Path;
+
+#[derive(Serialize, Deserialize)]
+/// Persisted form of a catalog.
+struct Snapshot<P> {
+    places: P,
+}
+
+/// Normalized search fields of a place.
+struct Entry {
+    name: String,
+    words: Vec<String>,
+    state: String,
+    country: [String; 2],
+}
+
+impl Entry {
+    fn new(place: &Place) -> Self {
+        let name = normalize(&place.geo.name);
+        let words = name.split(' ').map(String::from).collect();
+
+        Self {
+            name,
+            words,
+            state: place
+                .geo
+                .state
+                .as_deref()
+                .map(normalize)
+                .unwrap_or_default(),
+            country: [
+                normalize(&place.geo.country.id),
+                normalize(&place.geo.country.name),
+            ],
+        }
+    }
+
+    /// Score a normalized search text between 0 and 1, or `None` if it does not match.
+    fn score(&self, text: &str) -> Option<f32> {
+        let region =
+            || self.state.starts_with(text) || self.country.iter().any(|c| c.starts_with(text));
+
+        if self.name == text {
+            Some(1.0)
+        } else if self.name.starts_with(text) {
+            Some(0.9)
+        } else if self.words.iter().any(|word| word.starts_with(text)) {
+            Some(0.8)
+        } else if region() {
+            Some(0.6)
+        } else {
+            let score = similarity(text, &self.name) * 0.75;
+
+            Some(score).filter(|&score| score >= PlaceCatalog::FUZZY)
+        }
+    }
+
+    /// Normalized texts which searches look up by prefix.
+    fn terms(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(&self.name)
+            .chain(&self.words)
+            .chain(std::iter::once(&self.state))
+            .chain(&self.country)
+            .map(String::as_str)
+            .filter(|term| !term.is_empty())
+    }
+}
+
+/// Local copy of every place supported by the API, for searching without API calls.
+///
+/// The catalog is downloaded once with [`ServiceClient::load_place_catalog`] and can be saved to
+/// and opened from a file. Searches match names by prefix and by similarity, as well as states
+/// and countries by prefix. Names, their words, states and countries are kept in a sorted index,
+/// so a search only scores places sharing the first letters of the text; similar names must
+/// therefore start with the same two letters.
+///
+/// Example:
+/// ```
+/// use libtad_rs::{
+///     ServiceClient,
+///     service::places::PlaceCatalog,
+/// };
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into());
+/// let catalog = client.load_place_catalog();
+/// ```
+///
+/// [`ServiceClient::load_place_catalog`]: crate::ServiceClient::load_place_catalog
+pub struct PlaceCatalog {
+    places: Vec<Place>,
+    entries: Vec<Entry>,
+    index: Vec<(String, usize)>,
+}
+
+impl PlaceCatalog {
+    /// Lowest score of fuzzy matches.
+    const FUZZY: f32 = 0.5;
+
+    /// Number of leading characters a place must share with the text to be scored.
+    const PREFIX: usize = 2;
+
+    /// Create a catalog from a list of places.
+    pub fn new(places: Vec<Place>) -> Self {
+        let entries: Vec<_> = places.iter().map(Entry::new).collect();
+
+        let mut index: Vec<_> = entries
+            .iter()
+            .enumerate()
+            .flat_map(|(i, entry)| entry.terms().map(move |term| (term.to_owned(), i)))
+            .collect();
+        index.sort();
+        index.dedup();
+
+        Self {
+            places,
+            entries,
+            index,
+        }
+    }
+
+    pub(crate) fn request() -> PlacesRequest {
+        PlacesRequest::new().set_geo(true)
+    }
+
+    /// All places in the catalog.
+    pub fn places(&self) -> &[Place] {
+        &self.places
+    }
+
+    /// Number of places in the catalog.
+    pub fn len(&self) -> usize {
+        self.places.len()
+    }
+
+    /// Whether the catalog has no places.
+    pub fn is_empty(&self) -> bool {
+        self.places.is_empty()
+    }
+
+    /// Look up a place by its numerical id.
+    pub fn get(&self, id: i32) -> Option<&Place> {
+        self.places.iter().find(|place| place.id == id)
+    }
+
+    /// Search for places by name, state or country, best matches first.
+    ///
+    /// Names starting with the text rank above names where a later word starts with it, which
+    /// rank above matching states or countries and names which are merely similar.
+    pub fn search(&self, text: &str, limit: usize) -> Vec<&Place> {
+        let text = normalize(text);
+
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<_> = self
+            .candidates(&text)
+            .into_iter()
+            .filter_map(|i| {
+                let entry = &self.entries[i];
+
+                Some((entry.score(&text)?, entry, &self.places[i]))
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            b.0.total_cmp(&a.0)
+                .then_with(|| a.1.name.cmp(&b.1.name))
+                .then_with(|| a.2.id.cmp(&b.2.id))
+        });
+
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, place)| place)
+            .collect()
+    }
+
+    /// Entries with a term starting with the first characters of a normalized text. Every prefix
+    /// match of the whole text is among them.
+    fn candidates(&self, text: &str) -> BTreeSet<usize> {
+        let prefix: String = text.chars().take(Self::PREFIX).collect();
+        let start = self
+            .index
+            .partition_point(|(term, _)| term.as_str() < prefix.as_str());
+
+        self.index[start..]
+            .iter()
+            .take_while(|(term, _)| term.starts_with(&prefix))
+            .map(|(_, i)| *i)
+            .collect()
+    }
+
+    /// Places nearest to a coordinate, nearest first. Places without coordinates are skipped.
+    pub fn nearest(&self, latitude: f32, longitude: f32, limit: usize) -> Vec<(&Place, Distance)> {
+        let mut places: Vec<_> = self
+            .places
+            .iter()
+            .filter_map(|place| Some((place, place.geo.distance_to(latitude, longitude)?)))
+            .collect();
+
+        places.sort_by(|a, b| a.1.km().total_cmp(&b.1.km()));
+        places.truncate(limit);
+
+        places
+    }
+
+    /// Changes from this catalog to a newer snapshot, matched by place id.
+    pub fn diff<'a>(&'a self, newer: &'a PlaceCatalog) -> PlaceCatalogDiff<'a> {
+        let old: BTreeMap<_, _> = self.places.iter().map(|p| (p.id, p)).collect();
+        let new: BTreeMap<_, _> = newer.places.iter().map(|p| (p.id, p)).collect();
+
+        PlaceCatalogDiff {
+            added: new
+                .iter()
+                .filter(|(id, _)| !old.contains_key(id))
+                .map(|(_, place)| *place)
+                .collect(),
+            removed: old
+                .iter()
+                .filter(|(id, _)| !new.contains_key(id))
+                .map(|(_, place)| *place)
+                .collect(),
+            changed: old
+                .iter()
+                .filter_map(|(id, before)| Some((*before, *new.get(id)?)))
+                .filter(|(before, after)| before != after)
+                .collect(),
+        }
+    }
+
+    /// Write the catalog as JSON.
+    pub fn to_writer(&self, writer: impl io::Write) -> serde_json::Result<()> {
+        serde_json::to_writer(
+            writer,
+            &Snapshot {
+                places: &self.places,
+            },
+        )
+    }
+
+    /// Read a catalog written by [`PlaceCatalog::to_writer`].
+    pub fn from_reader(reader: impl io::Read) -> serde_json::Result<Self> {
+        let snapshot: Snapshot<Vec<Place>> = serde_json::from_reader(reader)?;
+
+        Ok(Self::new(snapshot.places))
+    }
+
+    /// Save the catalog to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = io::BufWriter::new(std::fs::File::create(path)?);
+
+        Ok(self.to_writer(file)?)
+    }
+
+    /// Open a catalog saved with [`PlaceCatalog::save`].
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = io::BufReader::new(std::fs::File::open(path)?);
+
+        Ok(Self::from_reader(file)?)
+    }
+}
+
+impl From<PlacesResponse> for PlaceCatalog {
+    fn from(response: PlacesResponse) -> Self {
+        Self::new(response.places)
+    }
+}
+
+#[derive(Debug)]
+/// Changes between two catalog snapshots.
+pub struct PlaceCatalogDiff<'a> {
+    /// Places only in the newer snapshot.
+    pub added: Vec<&'a Place>,
+
+    /// Places only in the older snapshot.
+    pub removed: Vec<&'a Place>,
+
+    /// Places in both snapshots which changed, before and after.
+    pub changed: Vec<(&'a Place, &'a Place)>,
+}
+
+impl PlaceCatalogDiff<'_> {
+    /// Whether the snapshots are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
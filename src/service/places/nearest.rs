@@ -0,0 +1,19 @@
+use crate::models::{places::Location, Distance};
+
+#[derive(Debug, Clone)]
+/// The place nearest to a coordinate.
+pub struct NearestPlace {
+    /// The matched location.
+    pub location: Location,
+
+    /// Distance from the coordinate to the location, if its coordinates are known.
+    pub distance: Option<Distance>,
+}
+
+impl NearestPlace {
+    pub(crate) fn new(location: Location, latitude: f32, longitude: f32) -> Self {
+        let distance = location.geo.distance_to(latitude, longitude);
+
+        Self { location, distance }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::service::Validate;
+use crate::ValidationError;
 use serde::Serialize;
 
 #[derive(Default, Serialize)]
@@ -39,7 +41,7 @@ impl PlacesRequest {
         if let Some(ref mut placeids) = self.placeid {
             placeids.push(placeid.into());
         } else {
-            self.placeid.insert(vec![placeid.into()]);
+            self.placeid = Some(vec![placeid.into()]);
         }
 
         self
@@ -47,29 +49,44 @@ impl PlacesRequest {
 
     /// Set the query for the request.
     pub fn set_query(mut self, query: impl Into<String>) -> Self {
-        self.query.insert(query.into());
+        self.query = Some(query.into());
 
         self
     }
 
     /// Set the maximum number of query results to be returned.
     pub fn set_qlimit(mut self, qlimit: u8) -> Self {
-        self.qlimit.insert(qlimit);
+        self.qlimit = Some(qlimit);
 
         self
     }
 
     /// Set the request language for the request.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Toggle whether to return longitude and latitude for the geo object.
     pub fn set_geo(mut self, enable: bool) -> Self {
-        self.geo.insert(enable.into());
+        self.geo = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for PlacesRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(qlimit) = self.qlimit {
+            if !(1..=100).contains(&qlimit) {
+                return Err(ValidationError::new(
+                    "qlimit",
+                    format!("{} is not between 1 and 100", qlimit),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
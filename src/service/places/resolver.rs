@@ -0,0 +1,308 @@
+use super::{PlacesRequest, PlacesResponse};
+use crate::models::places::{Place, PlaceId};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A place to resolve: a free-form name, optionally with hints about where it is.
+///
+/// Qualifiers after a comma in the name, as in "Portland, Maine", are matched against the state
+/// and country of the candidates.
+pub struct PlaceQuery {
+    text: String,
+    name: String,
+    qualifiers: Vec<String>,
+    country: Option<String>,
+    state: Option<String>,
+    near: Option<(f32, f32)>,
+}
+
+impl PlaceQuery {
+    /// Start building a new query for a place name.
+    pub fn new(name: impl AsRef<str>) -> Self {
+        let mut parts = name.as_ref().split(',');
+        let text = parts.next().unwrap_or_default().trim();
+
+        Self {
+            text: text.into(),
+            name: normalize(text),
+            qualifiers: parts
+                .map(normalize)
+                .filter(|part| !part.is_empty())
+                .collect(),
+            country: None,
+            state: None,
+            near: None,
+        }
+    }
+
+    /// Prefer places in a country, given by its ISO 3166-1-alpha-2 code or name.
+    pub fn set_country(mut self, country: impl AsRef<str>) -> Self {
+        self.country = Some(normalize(country.as_ref()));
+
+        self
+    }
+
+    /// Prefer places in a state/subdivision.
+    pub fn set_state(mut self, state: impl AsRef<str>) -> Self {
+        self.state = Some(normalize(state.as_ref()));
+
+        self
+    }
+
+    /// Prefer places close to a coordinate.
+    pub fn set_near(mut self, latitude: f32, longitude: f32) -> Self {
+        self.near = Some((latitude, longitude));
+
+        self
+    }
+
+    /// Key identifying the query in the resolver cache.
+    fn key(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{:?}",
+            self.name,
+            self.qualifiers.join(","),
+            self.country.as_deref().unwrap_or_default(),
+            self.state.as_deref().unwrap_or_default(),
+            self.near,
+        )
+    }
+
+    /// Score a candidate between 0 and 1. Only criteria the query has hints for are weighed,
+    /// with the position in the API results as a proxy for population.
+    fn score(&self, place: &Place, rank: usize) -> f32 {
+        let geo = &place.geo;
+        let country = [normalize(&geo.country.id), normalize(&geo.country.name)];
+        let state = geo.state.as_deref().map(normalize);
+
+        let in_country = |hint: &str| country.iter().any(|c| c == hint);
+        let in_state = |hint: &str| state.as_deref().is_some_and(|s| matches(s, hint));
+
+        let mut criteria = vec![
+            (3.0, similarity(&self.name, &normalize(&geo.name))),
+            (1.0, 1.0 / (1.0 + rank as f32)),
+        ];
+
+        if let Some(hint) = &self.country {
+            criteria.push((2.0, in_country(hint) as u8 as f32));
+        }
+
+        if let Some(hint) = &self.state {
+            criteria.push((2.0, in_state(hint) as u8 as f32));
+        }
+
+        for hint in &self.qualifiers {
+            criteria.push((2.0, (in_country(hint) || in_state(hint)) as u8 as f32));
+        }
+
+        if let Some((latitude, longitude)) = self.near {
+            let proximity = geo
+                .distance_to(latitude, longitude)
+                .map_or(0.0, |distance| 1.0 / (1.0 + distance.km() / 100.0));
+
+            criteria.push((1.0, proximity));
+        }
+
+        let total: f32 = criteria.iter().map(|(weight, _)| weight).sum();
+
+        criteria
+            .iter()
+            .map(|(weight, score)| weight * score)
+            .sum::<f32>()
+            / total
+    }
+}
+
+#[derive(Debug, Clone)]
+/// The best match for a [`PlaceQuery`].
+pub struct Resolution {
+    /// The matched place.
+    pub place: Place,
+
+    /// Confidence in the match between 0 and 1. It is the score of the best candidate, reduced
+    /// as the score of the runner-up approaches it.
+    pub confidence: f32,
+}
+
+impl Resolution {
+    /// Id of the matched place, usable by every other service.
+    pub fn place_id(&self) -> PlaceId {
+        PlaceId::from(&self.place)
+    }
+}
+
+#[derive(Default)]
+/// Resolves free-form place names to places, ranking the candidates of the *Places* service.
+///
+/// Candidates are ranked by name similarity, country and state hints, coordinate proximity and
+/// their order in the API results. Resolutions are cached by query.
+///
+/// Example:
+/// ```
+/// use libtad_rs::{
+///     ServiceClient,
+///     service::places::{PlaceQuery, PlaceResolver},
+/// };
+///
+/// let client = ServiceClient::new("access_key".into(), "secret_key".into());
+/// let mut resolver = PlaceResolver::new();
+///
+/// let query = PlaceQuery::new("Portland").set_state("Maine");
+/// let resolution = client.resolve_place(&mut resolver, &query);
+/// ```
+pub struct PlaceResolver {
+    cache: HashMap<String, Option<Resolution>>,
+}
+
+impl PlaceResolver {
+    /// Number of candidates requested from the API.
+    const CANDIDATES: u8 = 20;
+
+    /// Create a resolver with an empty cache.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The cached resolution of a query, if it has been resolved before.
+    pub fn cached(&self, query: &PlaceQuery) -> Option<&Option<Resolution>> {
+        self.cache.get(&query.key())
+    }
+
+    /// Remove all cached resolutions.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub(crate) fn request(&self, query: &PlaceQuery) -> PlacesRequest {
+        PlacesRequest::new()
+            .set_query(query.text.clone())
+            .set_qlimit(Self::CANDIDATES)
+            .set_geo(true)
+    }
+
+    pub(crate) fn insert(&mut self, query: &PlaceQuery, response: PlacesResponse) {
+        let resolution = Self::rank(query, response.places);
+
+        self.cache.insert(query.key(), resolution);
+    }
+
+    /// Pick the best candidate for a query.
+    pub fn rank(query: &PlaceQuery, places: Vec<Place>) -> Option<Resolution> {
+        let mut scored: Vec<_> = places
+            .into_iter()
+            .enumerate()
+            .map(|(rank, place)| (query.score(&place, rank), place))
+            .collect();
+
+        scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mut scored = scored.into_iter();
+        let (best, place) = scored.next()?;
+        let runner_up = scored.next().map_or(0.0, |(score, _)| score);
+
+        Some(Resolution {
+            place,
+            confidence: best * (1.0 - runner_up / best.max(f32::EPSILON)).sqrt(),
+        })
+    }
+}
+
+/// Lowercase a name, folding common diacritics and abbreviations and dropping punctuation.
+pub(super) fn normalize(name: &str) -> String {
+    let folded: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            c if c.is_alphanumeric() => c,
+            _ => ' ',
+        })
+        .collect();
+
+    folded
+        .split_whitespace()
+        .map(|word| match word {
+            "st" => "saint",
+            "ste" => "sainte",
+            "mt" => "mount",
+            "ft" => "fort",
+            word => word,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether a normalized name matches a hint, either fully or by prefix.
+fn matches(name: &str, hint: &str) -> bool {
+    !hint.is_empty() && name.starts_with(hint)
+}
+
+/// Similarity of two normalized names between 0 and 1. Prefix matches count as close.
+pub(super) fn similarity(query: &str, name: &str) -> f32 {
+    if query == name {
+        return 1.0;
+    }
+
+    let len = query.chars().count().max(name.chars().count());
+    let similarity = 1.0 - distance(query, name) as f32 / len.max(1) as f32;
+
+    if matches(name, query) {
+        similarity.max(0.9)
+    } else {
+        similarity
+    }
+}
+
+/// Levenshtein edit distance.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(current).min(row[j])
+            };
+            previous = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[test]
+fn normalize_names() {
+    assert!(normalize("  St. Petersburg ") == "saint petersburg");
+    assert!(normalize("Tromsø") == "tromso");
+    assert!(normalize("Mt Vernon") == "mount vernon");
+}
+
+#[test]
+fn queries_keep_the_name_as_written() {
+    let query = PlaceQuery::new(" St. Petersburg , Florida");
+
+    assert!(query.text == "St. Petersburg");
+    assert!(query.name == "saint petersburg");
+    assert!(query.qualifiers == ["florida"]);
+}
+
+#[test]
+fn edit_distance() {
+    assert!(distance("kitten", "sitting") == 3);
+    assert!(distance("", "oslo") == 4);
+    assert!(similarity("oslo", "oslo") == 1.0);
+}
@@ -1,6 +1,8 @@
 use super::Service;
 use crate::{ApiError, Error, ServiceClient};
+use chrono::Duration;
 use maybe_async::maybe_async;
+use url::Url;
 
 mod request;
 mod response;
@@ -11,6 +13,9 @@ pub use request::TidesRequest;
 /// Tides API response.
 pub use response::TidesResponse;
 
+/// Longest time interval, in days, requested at once when splitting tides requests.
+const MAX_RANGE_DAYS: i64 = 7;
+
 struct TidesService;
 
 impl Service for TidesService {
@@ -30,4 +35,70 @@ impl ServiceClient {
     ) -> Result<Result<TidesResponse, ApiError>, Error> {
         self.call::<TidesService>(request).await
     }
+
+    /// Signed URL for a request to the *Tides* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_tidal_data_url(&self, request: &TidesRequest) -> Result<Url, Error> {
+        self.sign_url::<TidesService>(request)
+    }
+
+    #[maybe_async]
+    /// Retrieve predicted tidal data for a time interval of any length.
+    ///
+    /// Long intervals are split into several requests to the *Tides* service, and the results are
+    /// combined into a single response without duplicate data points.
+    pub async fn get_tidal_data_range(
+        &self,
+        request: &TidesRequest,
+    ) -> Result<Result<TidesResponse, ApiError>, Error> {
+        let mut response = TidesResponse { stations: vec![] };
+
+        for chunk in request.split(Duration::days(MAX_RANGE_DAYS)) {
+            match self.get_tidal_data(&chunk).await? {
+                Ok(r) => response.merge(r),
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+
+        Ok(Ok(response))
+    }
+
+    #[cfg(feature = "__async")]
+    /// Stream predicted tidal data point by point, station by station. Every data point is paired
+    /// with the matchparam of its station.
+    ///
+    /// The time interval is fetched lazily in parts, so large intervals can be processed without
+    /// waiting for all data. The stream ends after the first error.
+    pub fn stream_tidal_data(
+        &self,
+        request: &TidesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<Result<(String, crate::models::tides::Tide), ApiError>, Error>,
+    > + '_ {
+        let pages = request
+            .split_by_place()
+            .iter()
+            .flat_map(|request| request.split(Duration::days(MAX_RANGE_DAYS)))
+            .collect();
+
+        crate::paging::paginate(pages, move |page: TidesRequest| async move {
+            let response = self.get_tidal_data(&page).await?;
+
+            Ok(response.map(|response| {
+                response
+                    .stations
+                    .into_iter()
+                    .flat_map(|station| {
+                        let matchparam = station.matchparam;
+
+                        station.result.into_iter().map(move |tide| {
+                            let key = (matchparam.clone(), tide.time.iso.clone());
+
+                            (key, (matchparam.clone(), tide))
+                        })
+                    })
+                    .collect()
+            }))
+        })
+    }
 }
@@ -1,5 +1,8 @@
-use crate::models::time::DateTime;
-use crate::service::{ProvidedArgument, RequiredArgument};
+use crate::convert::split_range;
+use crate::models::{tides::TideInterval, time::DateTime, Distance};
+use crate::service::{validate_range, ProvidedArgument, RequiredArgument, Validate};
+use crate::ValidationError;
+use chrono::Duration;
 use serde::Serialize;
 
 macro_rules! return_type {
@@ -18,7 +21,7 @@ macro_rules! return_type {
     };
 }
 
-#[derive(Default, Serialize)]
+#[derive(Default, Clone, Serialize)]
 /// Tides API request.
 ///
 /// Request is validated when supplied to the client.
@@ -29,7 +32,7 @@ pub struct TidesRequest<A = ProvidedArgument> {
     enddt: Option<DateTime>,
     radius: Option<i32>,
     subordinate: Option<u8>,
-    interval: Option<i32>,
+    interval: Option<TideInterval>,
     localtime: Option<u8>,
     _a: std::marker::PhantomData<A>,
 }
@@ -39,6 +42,37 @@ impl TidesRequest {
     pub fn new() -> TidesRequest<RequiredArgument> {
         Default::default()
     }
+
+    /// Split the request into requests covering at most `span` of the time interval each.
+    pub(crate) fn split(&self, span: Duration) -> Vec<Self> {
+        let (startdt, enddt) = match (self.startdt, self.enddt) {
+            (Some(startdt), Some(enddt)) => (startdt, enddt),
+            _ => return vec![self.clone()],
+        };
+
+        split_range(&startdt, &enddt, span)
+            .into_iter()
+            .map(|(startdt, enddt)| {
+                let mut request = self.clone();
+                request.startdt = Some(startdt);
+                request.enddt = Some(enddt);
+                request
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "__async")]
+    /// Split the request into one request per placeid.
+    pub(crate) fn split_by_place(&self) -> Vec<Self> {
+        self.placeid
+            .iter()
+            .map(|placeid| {
+                let mut request = self.clone();
+                request.placeid = vec![placeid.clone()];
+                request
+            })
+            .collect()
+    }
 }
 
 impl<A> TidesRequest<A> {
@@ -51,50 +85,60 @@ impl<A> TidesRequest<A> {
 
     /// Set whether to return every point per interval or just the highest and lowest points.
     pub fn set_onlyhighlow(mut self, enable: bool) -> Self {
-        self.onlyhighlow.insert(enable.into());
+        self.onlyhighlow = Some(enable.into());
 
         self
     }
 
     /// Set start of the requested time interval.
     pub fn set_startdt(mut self, startdt: DateTime) -> Self {
-        self.startdt.insert(startdt);
+        self.startdt = Some(startdt);
 
         self
     }
 
     /// Set end of the requested time interval.
     pub fn set_enddt(mut self, enddt: DateTime) -> Self {
-        self.enddt.insert(enddt);
+        self.enddt = Some(enddt);
 
         self
     }
 
-    /// Set radius from the requested location to query for stations. Given in kilometers.
-    pub fn set_radius(mut self, radius: i32) -> Self {
-        self.radius.insert(radius);
+    /// Set radius from the requested location to query for stations. Rounded to whole
+    /// kilometers.
+    pub fn set_radius(mut self, radius: Distance) -> Self {
+        self.radius = Some(radius.km().round() as i32);
 
         self
     }
 
     /// Toggle whether to resolve subordinate stations or just reference stations.
     pub fn set_subordinate(mut self, enable: bool) -> Self {
-        self.subordinate.insert(enable.into());
+        self.subordinate = Some(enable.into());
 
         self
     }
 
-    /// Set how many minutes to calculate the range in. Supported: 5 min, 15, min, 30 min, 60 min.
-    pub fn set_interval(mut self, interval: i32) -> Self {
-        self.interval.insert(interval);
+    /// Set the interval between data points.
+    pub fn set_interval(mut self, interval: TideInterval) -> Self {
+        self.interval = Some(interval);
 
         self
     }
 
     /// Toggle whether input and output time stamps should be resolved to local time.
     pub fn set_localtime(mut self, enable: bool) -> Self {
-        self.localtime.insert(enable.into());
+        self.localtime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for TidesRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match (&self.startdt, &self.enddt) {
+            (Some(startdt), Some(enddt)) => validate_range(startdt, enddt),
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use libtad_models::tides::Station;
 use serde::Deserialize;
+use std::collections::HashSet;
 
 #[derive(Debug, Deserialize)]
 /// Tides API response.
@@ -7,3 +8,33 @@ pub struct TidesResponse {
     /// List of returned stations.
     pub stations: Vec<Station>,
 }
+
+impl TidesResponse {
+    /// Merge a response for an adjacent time interval into this one. Data points which are already
+    /// present are skipped, so intervals may overlap at their boundaries.
+    pub(crate) fn merge(&mut self, other: Self) {
+        for station in other.stations {
+            let existing = self.stations.iter_mut().find(|s| {
+                s.matchparam == station.matchparam && s.source.name == station.source.name
+            });
+
+            let existing = match existing {
+                Some(existing) => existing,
+                None => {
+                    self.stations.push(station);
+                    continue;
+                }
+            };
+
+            let known: HashSet<String> =
+                existing.result.iter().map(|t| t.time.iso.clone()).collect();
+
+            existing.result.extend(
+                station
+                    .result
+                    .into_iter()
+                    .filter(|tide| !known.contains(&tide.time.iso)),
+            );
+        }
+    }
+}
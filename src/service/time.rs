@@ -1,6 +1,10 @@
 use super::Service;
+use crate::convert::from_naive_datetime;
+use crate::models::time::{DateTime, Time, TimeChange};
 use crate::{ApiError, Error, ServiceClient};
+use chrono::{Datelike, FixedOffset, NaiveDateTime, Utc};
 use maybe_async::maybe_async;
+use url::Url;
 
 mod request;
 mod response;
@@ -47,6 +51,12 @@ impl ServiceClient {
         self.call::<ConvertTimeService>(request).await
     }
 
+    /// Signed URL for a request to the *ConvertTime* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn convert_time_url(&self, request: &ConvertTimeRequest) -> Result<Url, Error> {
+        self.sign_url::<ConvertTimeService>(request)
+    }
+
     #[maybe_async]
     /// The *DSTList* service can be used to obtain data about time zones for all supported countries in our database.
     /// This includes the start and end date of daylight savings time, and UTC offset for the time zones.
@@ -57,6 +67,12 @@ impl ServiceClient {
         self.call::<DSTListService>(request).await
     }
 
+    /// Signed URL for a request to the *DSTList* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_daylight_savings_time_url(&self, request: &DSTListRequest) -> Result<Url, Error> {
+        self.sign_url::<DSTListService>(request)
+    }
+
     #[maybe_async]
     /// The *Timeservice* service can be used to retrieve the current time in one or more places.
     /// Additionally, information about time zones and related changes and the time of sunrise and sunset can be queried.
@@ -66,4 +82,154 @@ impl ServiceClient {
     ) -> Result<Result<TimeserviceResponse, ApiError>, Error> {
         self.call::<TimeserviceService>(request).await
     }
+
+    /// Signed URL for a request to the *Timeservice* service. The URL can be fetched directly
+    /// without access to the secret key, as long as the server accepts its timestamp.
+    pub fn get_current_time_url(&self, request: &TimeserviceRequest) -> Result<Url, Error> {
+        self.sign_url::<TimeserviceService>(request)
+    }
+
+    #[maybe_async]
+    /// Current time in a place, with its offset from UTC.
+    ///
+    /// Example:
+    /// ```
+    /// use libtad_rs::ServiceClient;
+    ///
+    /// let client = ServiceClient::new("access_key".into(), "secret_key".into());
+    /// let now = client.now_in("norway/oslo");
+    /// ```
+    pub async fn now_in(
+        &self,
+        place: impl Into<String>,
+    ) -> Result<Result<Option<chrono::DateTime<FixedOffset>>, ApiError>, Error> {
+        let request = TimeserviceRequest::new()
+            .set_placeid(place)
+            .set_time(true)
+            .set_tz(true);
+
+        Ok(self
+            .get_current_time(&request)
+            .await?
+            .map(|response| response.locations.first()?.time.as_ref()?.local()))
+    }
+
+    #[maybe_async]
+    /// Convert a local time in one place to the local time in another place.
+    pub async fn convert(
+        &self,
+        datetime: NaiveDateTime,
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Result<Result<Option<chrono::DateTime<FixedOffset>>, ApiError>, Error> {
+        let request = ConvertTimeRequest::new()
+            .set_fromid(from)
+            .with_toid(to)
+            .set_datetime(from_naive_datetime(datetime))
+            .set_tz(true);
+
+        // The place converted from is listed first, followed by the place converted to.
+        Ok(self
+            .convert_time(&request)
+            .await?
+            .map(|response| response.locations.last()?.time.as_ref()?.local()))
+    }
+
+    #[maybe_async]
+    /// Offset from UTC in a place at a local time, including daylight savings time.
+    pub async fn offset_of(
+        &self,
+        place: impl Into<String>,
+        at: NaiveDateTime,
+    ) -> Result<Result<Option<FixedOffset>, ApiError>, Error> {
+        let request = ConvertTimeRequest::new()
+            .set_fromid(place)
+            .set_datetime(from_naive_datetime(at))
+            .set_tz(true);
+
+        Ok(self.convert_time(&request).await?.map(|response| {
+            let time = response.locations.first()?.time.as_ref()?;
+
+            time.timezone.as_ref()?.utc_offset()
+        }))
+    }
+
+    #[maybe_async]
+    /// Next daylight savings time change in a place. Changes of the following year are looked up
+    /// if there are no more changes this year.
+    pub async fn next_dst_change(
+        &self,
+        place: impl Into<String>,
+    ) -> Result<Result<Option<TimeChange>, ApiError>, Error> {
+        let place = place.into();
+        let request = TimeserviceRequest::new()
+            .set_placeid(place.clone())
+            .set_time(true)
+            .set_timechanges(true);
+
+        let location = match self.get_current_time(&request).await? {
+            Ok(response) => response.locations.into_iter().next(),
+            Err(e) => return Ok(Err(e)),
+        };
+
+        let location = match location {
+            Some(location) => location,
+            None => return Ok(Ok(None)),
+        };
+
+        let now = location
+            .time
+            .as_ref()
+            .and_then(Time::local)
+            .map_or_else(|| self.now(), |now| now.with_timezone(&Utc));
+
+        if let Some(change) = next_change(location.timechanges, now) {
+            return Ok(Ok(Some(change)));
+        }
+
+        let request = ConvertTimeRequest::new()
+            .set_fromid(place)
+            .set_datetime(DateTime {
+                year: now.year() + 1,
+                month: 1,
+                day: 1,
+                ..Default::default()
+            })
+            .set_timechanges(true);
+
+        Ok(self.convert_time(&request).await?.map(|response| {
+            let location = response.locations.into_iter().next()?;
+
+            next_change(location.timechanges, now)
+        }))
+    }
+
+    #[maybe_async]
+    /// Whether any region of a country observes daylight savings time in a year.
+    pub async fn observes_dst(
+        &self,
+        country: impl Into<String>,
+        year: i32,
+    ) -> Result<Result<bool, ApiError>, Error> {
+        let request = DSTListRequest::new()
+            .set_country(country)
+            .set_year(year)
+            .set_onlydst(true);
+
+        Ok(self
+            .get_daylight_savings_time(&request)
+            .await?
+            .map(|response| !response.dstlist.is_empty()))
+    }
+}
+
+/// First time change after a time stamp.
+fn next_change(
+    changes: Option<Vec<TimeChange>>,
+    after: chrono::DateTime<Utc>,
+) -> Option<TimeChange> {
+    changes?
+        .into_iter()
+        .filter(|change| change.utc() > Some(after))
+        .min_by_key(|change| change.utc())
 }
@@ -1,5 +1,5 @@
 use crate::models::time::DateTime;
-use crate::service::{ProvidedArgument, RequiredArgument};
+use crate::service::{ProvidedArgument, RequiredArgument, Validate};
 use serde::Serialize;
 
 macro_rules! return_type {
@@ -76,7 +76,7 @@ impl<A, B> ConvertTimeRequest<A, B> {
         if let Some(ref mut toids) = self.toid {
             toids.push(toid.into());
         } else {
-            self.toid.insert(vec![toid.into()]);
+            self.toid = Some(vec![toid.into()]);
         }
 
         self
@@ -91,36 +91,38 @@ impl<A, B> ConvertTimeRequest<A, B> {
 
     /// Set the request language.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Set search radius for translating coordinates to locations.
     pub fn set_radius(mut self, radius: i32) -> Self {
-        self.radius.insert(radius);
+        self.radius = Some(radius);
 
         self
     }
 
     /// Toggle whether to include time change information.
     pub fn set_timechanges(mut self, enable: bool) -> Self {
-        self.timechanges.insert(enable.into());
+        self.timechanges = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include time zone information.
     pub fn set_tz(mut self, enable: bool) -> Self {
-        self.tz.insert(enable.into());
+        self.tz = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include verbose time stamps.
     pub fn set_verbosetime(mut self, enable: bool) -> Self {
-        self.verbosetime.insert(enable.into());
+        self.verbosetime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for ConvertTimeRequest {}
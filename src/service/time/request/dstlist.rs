@@ -1,3 +1,5 @@
+use crate::service::Validate;
+use crate::ValidationError;
 use serde::Serialize;
 
 #[derive(Default, Serialize)]
@@ -35,50 +37,74 @@ impl DSTListRequest {
 
     /// Set year to query for.
     pub fn set_year(mut self, year: i32) -> Self {
-        self.year.insert(year);
+        self.year = Some(year);
 
         self
     }
 
     /// Set country to query for.
     pub fn set_country(mut self, country: impl Into<String>) -> Self {
-        self.country.insert(country.into());
+        self.country = Some(country.into());
 
         self
     }
 
     ///  Set request language.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Toggle whether to include individual places belonging to each country.
     pub fn set_listplaces(mut self, enable: bool) -> Self {
-        self.listplaces.insert(enable.into());
+        self.listplaces = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to only include places which observe DST.
     pub fn set_onlydst(mut self, enable: bool) -> Self {
-        self.onlydst.insert(enable.into());
+        self.onlydst = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include time change information.
     pub fn set_timechanges(mut self, enable: bool) -> Self {
-        self.timechanges.insert(enable.into());
+        self.timechanges = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include verbose time stamps.
     pub fn set_verbosetime(mut self, enable: bool) -> Self {
-        self.verbosetime.insert(enable.into());
+        self.verbosetime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for DSTListRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(year) = self.year {
+            if !(1..=9999).contains(&year) {
+                return Err(ValidationError::new(
+                    "year",
+                    format!("{} is not a valid year", year),
+                ));
+            }
+        }
+
+        if let Some(country) = &self.country {
+            if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ValidationError::new(
+                    "country",
+                    format!("{} is not a two letter country code", country),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::service::{ProvidedArgument, RequiredArgument};
+use crate::service::{ProvidedArgument, RequiredArgument, Validate};
 use serde::Serialize;
 
 macro_rules! return_type {
@@ -68,78 +68,80 @@ impl<A> TimeserviceRequest<A> {
         mut self,
         placeid: impl Into<String>,
     ) -> TimeserviceRequest<ProvidedArgument> {
-        self.placeid.insert(placeid.into());
+        self.placeid = Some(placeid.into());
 
         return_type!(self)
     }
 
     /// Set location query to get current time for.
     pub fn set_query(mut self, query: impl Into<String>) -> TimeserviceRequest<ProvidedArgument> {
-        self.query.insert(query.into());
+        self.query = Some(query.into());
 
         return_type!(self)
     }
 
     /// Set the limit for query results to be returned.
     pub fn set_qlimit(mut self, qlimit: u8) -> Self {
-        self.qlimit.insert(qlimit);
+        self.qlimit = Some(qlimit);
 
         self
     }
 
     /// Toggle whether to return longitude and latitude for the geo object.
     pub fn set_geo(mut self, enable: bool) -> Self {
-        self.geo.insert(enable.into());
+        self.geo = Some(enable.into());
 
         self
     }
 
     /// Set the request language.
     pub fn set_lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang.insert(lang.into());
+        self.lang = Some(lang.into());
 
         self
     }
 
     /// Set search radius for translating coordinates to locations.
     pub fn set_radius(mut self, radius: i32) -> Self {
-        self.radius.insert(radius);
+        self.radius = Some(radius);
 
         self
     }
 
     /// Toggle whether to include information about sunrise and sunset.
     pub fn set_sun(mut self, enable: bool) -> Self {
-        self.sun.insert(enable.into());
+        self.sun = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include current time under the location object.
     pub fn set_time(mut self, enable: bool) -> Self {
-        self.time.insert(enable.into());
+        self.time = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include time change information.
     pub fn set_timechanges(mut self, enable: bool) -> Self {
-        self.timechanges.insert(enable.into());
+        self.timechanges = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include time zone information.
     pub fn set_tz(mut self, enable: bool) -> Self {
-        self.tz.insert(enable.into());
+        self.tz = Some(enable.into());
 
         self
     }
 
     /// Toggle whether to include verbose time stamps.
     pub fn set_verbosetime(mut self, enable: bool) -> Self {
-        self.verbosetime.insert(enable.into());
+        self.verbosetime = Some(enable.into());
 
         self
     }
 }
+
+impl Validate for TimeserviceRequest {}
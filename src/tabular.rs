@@ -0,0 +1,675 @@
+use crate::convert::widen;
+use crate::models::{
+    astronomy::{AstronomyCurrent, AstronomyDay, AstronomyDayEvent},
+    date_calculator::Period,
+    onthisday::{Event, Person},
+    places::{Country, Geo, Location},
+    time::{DSTEntrySpecialType, DateTime, Time},
+    Text,
+};
+use crate::service::{
+    astronomy::AstronomyResponse,
+    date_calculator::{BusinessDateResponse, BusinessDurationResponse},
+    holidays::HolidaysResponse,
+    onthisday::OnThisDayResponse,
+    places::PlacesResponse,
+    tides::TidesResponse,
+    time::{ConvertTimeResponse, DSTListResponse, TimeserviceResponse},
+};
+use serde_json::Value;
+use std::io::{self, Write};
+
+/// Trait implemented by responses which can be exported as flat tables.
+///
+/// Nested structures are flattened into one row per innermost item, e.g. one row per tide for
+/// every station. Column names are stable and shared between the CSV and NDJSON output.
+///
+/// Example:
+/// ```
+/// use libtad_rs::{service::places::PlacesResponse, tabular::Tabular};
+///
+/// let response: PlacesResponse = serde_json::from_str(r#"{ "places": [] }"#).unwrap();
+///
+/// assert_eq!(response.to_csv().lines().next(), Some("id,urlid,name,state,country_id,country_name,latitude,longitude"));
+/// ```
+pub trait Tabular {
+    /// Column names of the table.
+    const COLUMNS: &'static [&'static str];
+
+    /// Rows of the table, with one value per column.
+    fn rows(&self) -> Vec<Vec<Value>>;
+
+    /// Write the table as CSV (RFC 4180), including a header row.
+    fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let header: Vec<_> = Self::COLUMNS.iter().map(|c| csv_field(c)).collect();
+        write!(writer, "{}\r\n", header.join(","))?;
+
+        for row in self.rows() {
+            let fields: Vec<_> = row.iter().map(csv_value).collect();
+            write!(writer, "{}\r\n", fields.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the table as newline-delimited JSON, with one object per row.
+    fn write_ndjson<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for row in self.rows() {
+            let fields: Vec<_> = Self::COLUMNS
+                .iter()
+                .zip(row.iter())
+                .map(|(column, value)| format!("{}:{}", Value::from(*column), value))
+                .collect();
+
+            writeln!(writer, "{{{}}}", fields.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    /// The table as CSV (RFC 4180), including a header row.
+    fn to_csv(&self) -> String {
+        let mut buffer = Vec::new();
+        self.write_csv(&mut buffer)
+            .expect("writing to a Vec cannot fail");
+
+        String::from_utf8(buffer).expect("table is valid UTF-8")
+    }
+
+    /// The table as newline-delimited JSON, with one object per row.
+    fn to_ndjson(&self) -> String {
+        let mut buffer = Vec::new();
+        self.write_ndjson(&mut buffer)
+            .expect("writing to a Vec cannot fail");
+
+        String::from_utf8(buffer).expect("table is valid UTF-8")
+    }
+}
+
+impl Tabular for HolidaysResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "uid",
+        "name",
+        "date",
+        "country_id",
+        "country_name",
+        "url",
+        "types",
+        "states",
+        "locations",
+        "oneliner",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        self.holidays
+            .iter()
+            .map(|h| {
+                vec![
+                    h.id.into(),
+                    h.uid.as_str().into(),
+                    text(&h.name),
+                    h.date.iso.as_str().into(),
+                    country_id(h.country.as_ref()),
+                    country_name(h.country.as_ref()),
+                    h.url.as_str().into(),
+                    list(h.types.iter().flatten()),
+                    list(h.states.iter().flatten().map(|s| &s.iso)),
+                    h.locations.as_deref().into(),
+                    h.oneliner.as_deref().map(text).unwrap_or(Value::Null),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Tabular for TidesResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "station_name",
+        "station_type",
+        "station_latitude",
+        "station_longitude",
+        "station_distance",
+        "matchparam",
+        "time",
+        "amplitude",
+        "phase",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        self.stations
+            .iter()
+            .flat_map(|station| {
+                station.result.iter().map(move |tide| {
+                    vec![
+                        station.source.name.as_str().into(),
+                        station.source.r#type.as_str().into(),
+                        float(station.source.latitude),
+                        float(station.source.longitude),
+                        float(station.source.distance.km()),
+                        station.matchparam.as_str().into(),
+                        tide.time.iso.as_str().into(),
+                        float(tide.amplitude),
+                        variant(&tide.phase),
+                    ]
+                })
+            })
+            .collect()
+    }
+}
+
+impl Tabular for AstronomyResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "location_id",
+        "location_name",
+        "matchparam",
+        "object",
+        "kind",
+        "date",
+        "daylength",
+        "moonphase",
+        "event_type",
+        "hour",
+        "min",
+        "sec",
+        "isotime",
+        "utctime",
+        "altitude",
+        "azimuth",
+        "distance",
+        "illuminated",
+        "posangle",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        let mut rows = Vec::new();
+
+        for location in &self.locations {
+            for object in &location.astronomy.objects {
+                let prefix = |kind: &str| {
+                    vec![
+                        location.id.as_str().into(),
+                        location.geo.name.as_str().into(),
+                        location.matchparam.as_str().into(),
+                        variant(&object.name),
+                        kind.into(),
+                    ]
+                };
+
+                for day in object.days.iter().flatten() {
+                    if day.events.is_empty() {
+                        rows.push(astronomy_day_row(prefix("day"), day, None));
+                    }
+
+                    for event in &day.events {
+                        rows.push(astronomy_day_row(prefix("event"), day, Some(event)));
+                    }
+                }
+
+                for current in object.current.iter() {
+                    rows.push(astronomy_current_row(prefix("current"), current));
+                }
+
+                for result in object.results.iter().flatten() {
+                    rows.push(astronomy_current_row(prefix("position"), result));
+                }
+            }
+        }
+
+        rows
+    }
+}
+
+impl Tabular for DSTListResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "country_id",
+        "country_name",
+        "region",
+        "biggestplace",
+        "std_offset",
+        "std_zoneabb",
+        "std_totaloffset",
+        "dst_offset",
+        "dst_zoneabb",
+        "dst_totaloffset",
+        "special",
+        "dststart",
+        "dstend",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        self.dstlist
+            .iter()
+            .map(|entry| {
+                let dst = entry.dsttimezone.as_ref();
+
+                vec![
+                    entry.region.country.id.as_str().into(),
+                    entry.region.country.name.as_str().into(),
+                    entry.region.desc.as_str().into(),
+                    entry.region.biggestplace.as_str().into(),
+                    entry.stdtimezone.offset.as_str().into(),
+                    entry.stdtimezone.zoneabb.as_str().into(),
+                    entry.stdtimezone.zonetotaloffset.into(),
+                    dst.map(|t| t.offset.as_str()).into(),
+                    dst.map(|t| t.zoneabb.as_str()).into(),
+                    dst.map(|t| t.zonetotaloffset).into(),
+                    entry
+                        .special
+                        .as_ref()
+                        .map(|s| match s.r#type {
+                            DSTEntrySpecialType::NoDST => "nodst",
+                            DSTEntrySpecialType::AllYear => "allyear",
+                        })
+                        .into(),
+                    entry.dststart.as_deref().into(),
+                    entry.dstend.as_deref().into(),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Tabular for PlacesResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "urlid",
+        "name",
+        "state",
+        "country_id",
+        "country_name",
+        "latitude",
+        "longitude",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        self.places
+            .iter()
+            .map(|place| {
+                let mut row = vec![place.id.into(), place.urlid.as_str().into()];
+                row.extend(geo(&place.geo));
+                row
+            })
+            .collect()
+    }
+}
+
+impl Tabular for OnThisDayResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "kind",
+        "id",
+        "name",
+        "date",
+        "birthdate",
+        "deathdate",
+        "location",
+        "categories",
+        "countries",
+        "nationalities",
+        "description",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        let events = self.events.iter().flatten().map(event_row);
+        let births = self.births.iter().flatten().map(|p| person_row("birth", p));
+        let deaths = self.deaths.iter().flatten().map(|p| person_row("death", p));
+
+        events.chain(births).chain(deaths).collect()
+    }
+}
+
+impl Tabular for TimeserviceResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "matchparam",
+        "name",
+        "state",
+        "country_id",
+        "country_name",
+        "latitude",
+        "longitude",
+        "time",
+        "zoneabb",
+        "offset",
+        "totaloffset",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        self.locations.iter().map(location_row).collect()
+    }
+}
+
+impl Tabular for ConvertTimeResponse {
+    const COLUMNS: &'static [&'static str] = &[
+        "utc",
+        "id",
+        "matchparam",
+        "name",
+        "state",
+        "country_id",
+        "country_name",
+        "latitude",
+        "longitude",
+        "time",
+        "zoneabb",
+        "offset",
+        "totaloffset",
+    ];
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        self.locations
+            .iter()
+            .map(|location| {
+                let mut row = vec![self.utc.time.iso.as_str().into()];
+                row.extend(location_row(location));
+                row
+            })
+            .collect()
+    }
+}
+
+impl Tabular for BusinessDateResponse {
+    const COLUMNS: &'static [&'static str] = PERIOD_COLUMNS;
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        self.periods
+            .iter()
+            .map(|p| period_row(&self.geo, p))
+            .collect()
+    }
+}
+
+impl Tabular for BusinessDurationResponse {
+    const COLUMNS: &'static [&'static str] = PERIOD_COLUMNS;
+
+    fn rows(&self) -> Vec<Vec<Value>> {
+        vec![period_row(&self.geo, &self.period)]
+    }
+}
+
+const PERIOD_COLUMNS: &[&str] = &[
+    "name",
+    "state",
+    "country_id",
+    "country_name",
+    "latitude",
+    "longitude",
+    "startdate",
+    "enddate",
+    "includeddays",
+    "calendardays",
+    "skippeddays",
+    "weekdays_type",
+    "weekdays_count",
+    "mon",
+    "tue",
+    "wed",
+    "thu",
+    "fri",
+    "sat",
+    "sun",
+    "holidays_type",
+    "holidays_count",
+    "holidays",
+];
+
+fn period_row(location: &Geo, period: &Period) -> Vec<Value> {
+    let weekdays = &period.weekdays;
+    let holidays = &period.holidays;
+
+    let mut row = geo(location);
+    row.extend(vec![
+        period.startdate.iso.as_str().into(),
+        period.enddate.iso.as_str().into(),
+        period.includeddays.into(),
+        period.calendardays.into(),
+        period.skippeddays.into(),
+        variant(&weekdays.r#type),
+        weekdays.count.into(),
+        weekdays.mon.into(),
+        weekdays.tue.into(),
+        weekdays.wed.into(),
+        weekdays.thu.into(),
+        weekdays.fri.into(),
+        weekdays.sat.into(),
+        weekdays.sun.into(),
+        holidays.r#type.as_ref().map(variant).unwrap_or(Value::Null),
+        holidays.count.into(),
+        list(holidays.list.iter().flatten().map(|h| &h.uid)),
+    ]);
+    row
+}
+
+fn location_row(location: &Location) -> Vec<Value> {
+    let time = location.time.as_ref();
+    let timezone = time.and_then(|t| t.timezone.as_ref());
+
+    let mut row = vec![
+        location.id.as_str().into(),
+        location.matchparam.as_str().into(),
+    ];
+    row.extend(geo(&location.geo));
+    row.extend(vec![
+        iso(time),
+        timezone.map(|t| t.zoneabb.as_str()).into(),
+        timezone.map(|t| t.offset.as_str()).into(),
+        timezone.map(|t| t.zonetotaloffset).into(),
+    ]);
+    row
+}
+
+fn astronomy_day_row(
+    mut row: Vec<Value>,
+    day: &AstronomyDay,
+    event: Option<&AstronomyDayEvent>,
+) -> Vec<Value> {
+    row.extend(vec![
+        date(&day.date),
+        day.daylength.map(duration).into(),
+        day.moonphase.as_ref().map(variant).unwrap_or(Value::Null),
+        event.map(|e| e.r#type.as_str()).into(),
+        event.map(|e| e.hour).into(),
+        event.map(|e| e.min).into(),
+        event.map(|e| e.sec).into(),
+        event
+            .and_then(|e| e.isotime.as_ref())
+            .map(datetime)
+            .unwrap_or(Value::Null),
+        event
+            .and_then(|e| e.utctime.as_ref())
+            .map(datetime)
+            .unwrap_or(Value::Null),
+        event.and_then(|e| e.altitude).map(float).into(),
+        event.and_then(|e| e.azimuth).map(float).into(),
+        event.and_then(|e| e.distance).map(float).into(),
+        event.and_then(|e| e.illuminated).map(float).into(),
+        event.and_then(|e| e.posangle).map(float).into(),
+    ]);
+    row
+}
+
+fn astronomy_current_row(mut row: Vec<Value>, current: &AstronomyCurrent) -> Vec<Value> {
+    let time = current.isotime.as_ref().or(current.utctime.as_ref());
+
+    row.extend(vec![
+        time.map(date).unwrap_or(Value::Null),
+        Value::Null,
+        current
+            .moonphase
+            .as_ref()
+            .map(variant)
+            .unwrap_or(Value::Null),
+        Value::Null,
+        time.map(|t| t.hour).into(),
+        time.map(|t| t.minute).into(),
+        time.map(|t| t.second).into(),
+        current
+            .isotime
+            .as_ref()
+            .map(datetime)
+            .unwrap_or(Value::Null),
+        current
+            .utctime
+            .as_ref()
+            .map(datetime)
+            .unwrap_or(Value::Null),
+        float(current.altitude),
+        float(current.azimuth),
+        float(current.distance),
+        current.illuminated.map(float).into(),
+        current.posangle.map(float).into(),
+    ]);
+    row
+}
+
+fn event_row(event: &Event) -> Vec<Value> {
+    vec![
+        "event".into(),
+        event.id.into(),
+        text(&event.name),
+        event.date.iso.as_str().into(),
+        Value::Null,
+        Value::Null,
+        event.location.as_deref().into(),
+        list(&event.categories),
+        list(event.countries.iter().map(|c| &c.id)),
+        Value::Null,
+        text(&event.description),
+    ]
+}
+
+fn person_row(kind: &str, person: &Person) -> Vec<Value> {
+    let name = [
+        person.name.first.as_deref(),
+        person.name.middle.as_deref(),
+        person.name.last.as_deref(),
+    ]
+    .iter()
+    .flatten()
+    .copied()
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let date = match kind {
+        "birth" => person.birthdate.as_ref(),
+        _ => person.deathdate.as_ref(),
+    };
+
+    vec![
+        kind.into(),
+        person.id.into(),
+        name.into(),
+        iso(date),
+        iso(person.birthdate.as_ref()),
+        iso(person.deathdate.as_ref()),
+        Value::Null,
+        list(person.categories.iter().flatten()),
+        Value::Null,
+        list(person.nationalities.iter().flatten()),
+        Value::Null,
+    ]
+}
+
+fn geo(geo: &Geo) -> Vec<Value> {
+    vec![
+        geo.name.as_str().into(),
+        geo.state.as_deref().into(),
+        geo.country.id.as_str().into(),
+        geo.country.name.as_str().into(),
+        geo.latitude.map(float).into(),
+        geo.longitude.map(float).into(),
+    ]
+}
+
+fn text(texts: &[Text]) -> Value {
+    texts.first().map(|t| t.text.as_str()).into()
+}
+
+fn iso(time: Option<&Time>) -> Value {
+    time.map(|t| t.iso.as_str()).into()
+}
+
+fn country_id(country: Option<&Country>) -> Value {
+    country.map(|c| c.id.as_str()).into()
+}
+
+fn country_name(country: Option<&Country>) -> Value {
+    country.map(|c| c.name.as_str()).into()
+}
+
+fn date(datetime: &DateTime) -> Value {
+    format!(
+        "{:04}-{:02}-{:02}",
+        datetime.year, datetime.month, datetime.day
+    )
+    .into()
+}
+
+fn datetime(datetime: &DateTime) -> Value {
+    datetime.to_string().into()
+}
+
+/// Number of a float column. Single precision values are widened without their binary noise.
+fn float(value: impl Float) -> Value {
+    value.widen().into()
+}
+
+trait Float {
+    fn widen(self) -> f64;
+}
+
+impl Float for f32 {
+    fn widen(self) -> f64 {
+        widen(self)
+    }
+}
+
+impl Float for f64 {
+    fn widen(self) -> f64 {
+        self
+    }
+}
+
+/// Join a list of values with semicolons, or null if the list is empty.
+fn list<T: AsRef<str>>(items: impl IntoIterator<Item = T>) -> Value {
+    let items: Vec<_> = items.into_iter().map(|i| i.as_ref().to_owned()).collect();
+
+    if items.is_empty() {
+        Value::Null
+    } else {
+        items.join(";").into()
+    }
+}
+
+/// Format a duration as "hh:mm:ss".
+fn duration(value: chrono::Duration) -> String {
+    let seconds = value.num_seconds();
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Name of an enum variant as returned by the API.
+fn variant<T: serde::Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+fn csv_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => csv_field(s),
+        other => csv_field(&other.to_string()),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
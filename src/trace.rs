@@ -0,0 +1,56 @@
+use crate::middleware::{Checked, Request, Response};
+use crate::observer::{Call, Outcome};
+use crate::Error;
+use std::time::Duration;
+use tracing::{field, Span};
+
+/// Open a span for a call to a service. The query does not include the access key or signature.
+pub(crate) fn span(request: &Request) -> Span {
+    tracing::info_span!(
+        "libtad",
+        path = request.path,
+        query = request.query.as_deref().unwrap_or_default(),
+        attempts = field::Empty,
+        latency_ms = field::Empty,
+        size = field::Empty,
+        api_error = field::Empty,
+        error = field::Empty,
+    )
+}
+
+/// Record the outcome of a call on its span. Warnings and errors are emitted within the span, so
+/// that they carry the path and query of the call.
+pub(crate) fn record(
+    span: &Span,
+    request: &Request,
+    latency: Duration,
+    response: &Result<Response, Error>,
+) {
+    let call = Call::new(request, latency, response);
+
+    span.record("attempts", call.attempts);
+    span.record("latency_ms", call.latency.as_millis() as u64);
+
+    if let Some(size) = call.size {
+        span.record("size", size as u64);
+    }
+
+    span.in_scope(|| match response {
+        Ok(response) => {
+            span.record("api_error", call.outcome == Outcome::ApiError);
+
+            match request.check(response) {
+                Checked::Success => {}
+                Checked::ApiError(errors) => tracing::warn!(?errors, "API error"),
+                Checked::Failed(_, e) => {
+                    span.record("error", field::display(&e));
+                    tracing::error!(error = %e, "invalid response");
+                }
+            }
+        }
+        Err(e) => {
+            span.record("error", field::display(e));
+            tracing::error!(error = %e, "request failed");
+        }
+    });
+}
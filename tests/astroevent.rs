@@ -1,16 +1,20 @@
+use chrono::{Duration, NaiveTime};
 use libtad_rs::models::{
-    astronomy::{AstronomyEventClass, AstronomyObjectType},
+    astronomy::{
+        AstronomyDay, AstronomyDayEventType, AstronomyEventClass, AstronomyLocation,
+        AstronomyObjectType,
+    },
     time::DateTime,
 };
 use libtad_rs::service::astronomy::AstroEventRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_events_for_multiple_days() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = AstroEventRequest::new()
         .with_object(AstronomyObjectType::Moon)
@@ -28,7 +32,7 @@ async fn request_events_for_multiple_days() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_events_with_lang() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = AstroEventRequest::new()
         .with_object(AstronomyObjectType::Sun)
@@ -45,7 +49,7 @@ async fn request_events_with_lang() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn test_geo_info_for_given_placeid() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = AstroEventRequest::new()
         .with_object(AstronomyObjectType::Moon)
@@ -67,7 +71,7 @@ async fn test_geo_info_for_given_placeid() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn test_event_info_for_given_placeid() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = AstroEventRequest::new()
         .with_object(AstronomyObjectType::Moon)
@@ -80,8 +84,8 @@ async fn test_event_info_for_given_placeid() {
         .as_ref()
         .unwrap()[0];
 
-    assert!(day.events[0].r#type == "set");
-    assert!(day.events[1].r#type == "rise");
+    assert!(day.events[0].r#type == AstronomyDayEventType::Set);
+    assert!(day.events[1].r#type == AstronomyDayEventType::Rise);
 
     assert!(day.events[0].azimuth == Some(294.4));
     assert!(day.events[1].azimuth == Some(66.0));
@@ -91,7 +95,7 @@ async fn test_event_info_for_given_placeid() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn requesting_current_event() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = AstroEventRequest::new()
         .with_object(AstronomyObjectType::Moon)
@@ -114,3 +118,97 @@ async fn requesting_current_event() {
     assert!(current.posangle.is_some());
     assert!(current.moonphase.is_some());
 }
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_events_for_a_year() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let request = AstroEventRequest::new()
+        .with_object(AstronomyObjectType::Sun)
+        .with_placeid("3")
+        .set_startdt(DateTime::from("2021-01-01"))
+        .set_enddt(DateTime::from("2021-12-31"))
+        .with_type(AstronomyEventClass::Meridian);
+
+    let response = client
+        .get_astro_events_range(&request)
+        .await
+        .unwrap()
+        .unwrap();
+    let days = response.locations[0].astronomy.objects[0].days.as_ref();
+
+    assert!(response.locations.len() == 1);
+    assert!(days.unwrap().len() == 365);
+}
+
+#[test]
+fn day_events_are_typed() {
+    let day: AstronomyDay = serde_json::from_str(
+        r#"{
+            "date": "2021-08-18",
+            "events": [
+                { "type": "civil_start", "hour": 4, "min": 33, "sec": 51 },
+                { "type": "rise", "hour": 5, "min": 34, "sec": 12 },
+                { "type": "meridian", "hour": 13, "min": 18, "sec": 24 },
+                { "type": "set", "hour": 21, "min": 2, "sec": 55 },
+                { "type": "eclipse", "hour": 22, "min": 0, "sec": 0 }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    assert!(day.civil_dawn().unwrap().hour == 4);
+    assert!(day.sunrise().unwrap().time() == NaiveTime::from_hms_opt(5, 34, 12));
+    assert!(day.sunrise().unwrap().min == 34);
+    assert!(day.solar_noon().unwrap().hour == 13);
+    assert!(day.sunset().unwrap().sec == 55);
+    assert!(day.civil_dusk().is_none());
+    assert!(day.events[4].r#type == AstronomyDayEventType::Unknown("eclipse".into()));
+}
+
+#[test]
+fn day_lengths_over_a_range() {
+    let location: AstronomyLocation = serde_json::from_str(
+        r#"{
+            "id": "1440",
+            "geo": { "name": "Tromsø", "country": { "id": "no", "name": "Norway" } },
+            "matchparam": "norway/tromso",
+            "astronomy": {
+                "objects": [
+                    {
+                        "name": "sun",
+                        "days": [
+                            { "date": "2021-05-17", "daylength": "22:57:06", "events": [
+                                { "type": "rise", "hour": 1, "min": 47, "sec": 43 },
+                                { "type": "set", "hour": 0, "min": 44, "sec": 49 }
+                            ] },
+                            { "date": "2021-05-18", "daylength": "24:00:00", "events": [] },
+                            { "date": "2021-05-19", "daylength": "24:00:00", "events": [] }
+                        ]
+                    }
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let days = location.sun_days();
+    assert!(days[0].daylength == Some(Duration::seconds(22 * 3600 + 57 * 60 + 6)));
+
+    assert!(location.longest_day().unwrap().date.day == 18);
+    assert!(location.shortest_day().unwrap().date.day == 17);
+
+    let deltas = location.daylength_deltas();
+    assert!(deltas.len() == 2);
+    assert!(deltas[0].1 == Duration::seconds(62 * 60 + 54));
+    assert!(deltas[1].1 == Duration::zero());
+
+    assert!(location.polar_days().len() == 2);
+    assert!(location.polar_nights().is_empty());
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -1,13 +1,13 @@
 use libtad_rs::models::{astronomy::AstronomyObjectType, time::DateTime};
 use libtad_rs::service::astronomy::AstroPositionRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_position_for_multiple_intervals() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let intervals: Vec<DateTime> = (1..5_i32)
         .zip(5..9)
@@ -31,7 +31,7 @@ async fn request_position_for_multiple_intervals() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_position_with_lang() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = AstroPositionRequest::new()
         .with_object(AstronomyObjectType::Moon)
@@ -48,7 +48,7 @@ async fn request_position_with_lang() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn test_geo_info_for_given_placeid() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = AstroPositionRequest::new()
         .with_object(AstronomyObjectType::Sun)
@@ -70,7 +70,7 @@ async fn test_geo_info_for_given_placeid() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn compare_with_and_without_localtime() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let mut request = AstroPositionRequest::new()
         .with_object(AstronomyObjectType::Moon)
@@ -97,3 +97,7 @@ async fn compare_with_and_without_localtime() {
     assert!(result_1.illuminated != result_2.illuminated);
     assert!(result_1.posangle != result_2.posangle);
 }
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -0,0 +1,39 @@
+use chrono::{Duration, TimeZone, Utc};
+use libtad_rs::{auth, Credentials, ServiceClient};
+
+#[test]
+fn sign_request() {
+    let credentials = Credentials::new("access", "secret");
+    let timestamp = Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap();
+
+    let signature = auth::sign(&credentials, "holidays", &timestamp).unwrap();
+
+    assert!(signature == "AuwQjC5G5pGbmHN6pczY6+8Jw+4=");
+}
+
+#[test]
+fn signature_depends_on_path_and_timestamp() {
+    let credentials = Credentials::new("access", "secret");
+    let timestamp = Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap();
+
+    let holidays = auth::sign(&credentials, "holidays", &timestamp).unwrap();
+    let tides = auth::sign(&credentials, "tides", &timestamp).unwrap();
+    let later = auth::sign(
+        &credentials,
+        "holidays",
+        &(timestamp + Duration::seconds(1)),
+    )
+    .unwrap();
+
+    assert!(holidays != tides);
+    assert!(holidays != later);
+}
+
+#[test]
+fn client_without_skew() {
+    let client = ServiceClient::new("access".into(), "secret".into())
+        .set_clock(|| Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap())
+        .set_skew_correction(true);
+
+    assert!(client.skew() == Duration::zero());
+}
@@ -0,0 +1,64 @@
+use libtad_rs::batch::Batch;
+use libtad_rs::service::time::TimeserviceRequest;
+use libtad_rs::{Credentials, ServiceClient};
+use maybe_async::maybe_async;
+use std::time::{Duration, Instant};
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn batch_returns_results_in_order() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let requests = ["179", "187", "+59.914+10.752"]
+        .iter()
+        .map(|&placeid| TimeserviceRequest::new().set_placeid(placeid));
+
+    let results = client.batch(requests, &Batch::new()).await;
+
+    assert!(results.len() == 3);
+
+    let (first, last) = match results.as_slice() {
+        [Ok(Ok(first)), Ok(Ok(_)), Ok(Ok(last))] => (first, last),
+        _ => panic!("expected three successful responses"),
+    };
+
+    assert!(first.locations[0].id == "179");
+    assert!(last.locations[0].geo.name == "Oslo");
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn batch_keeps_going_after_failures() {
+    let client = ServiceClient::new("invalid".into(), "invalid".into());
+
+    let requests = (0..5).map(|i| TimeserviceRequest::new().set_placeid(i.to_string()));
+    let results = client
+        .batch(requests, &Batch::new().set_concurrency(2))
+        .await;
+
+    assert!(results.len() == 5);
+    assert!(results.iter().all(|r| !matches!(r, Ok(Ok(_)))));
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn batch_respects_rate_limit() {
+    let client = ServiceClient::new("invalid".into(), "invalid".into());
+    let batch = Batch::new()
+        .set_concurrency(3)
+        .set_rate_limit(3, Duration::from_millis(300));
+
+    let start = Instant::now();
+    let requests = (0..3).map(|i| TimeserviceRequest::new().set_placeid(i.to_string()));
+    let results = client.batch(requests, &batch).await;
+
+    assert!(results.len() == 3);
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -1,13 +1,13 @@
 use libtad_rs::models::{date_calculator::BusinessDaysFilterType, time::DateTime};
 use libtad_rs::service::date_calculator::BusinessDateRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, Error, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_add_days() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
 
     let request = BusinessDateRequest::new()
@@ -43,7 +43,7 @@ async fn request_add_days() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_subtract_days() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2018-02-01");
 
     let request = BusinessDateRequest::new()
@@ -79,7 +79,7 @@ async fn request_subtract_days() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_add_days_repeating() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
 
     let request = BusinessDateRequest::new()
@@ -121,7 +121,7 @@ async fn request_add_days_repeating() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_add_days_with_invalid_repeat() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::new("access".into(), "secret".into());
     let startdate = DateTime::from("2017-12-01");
 
     let request = BusinessDateRequest::new()
@@ -132,13 +132,9 @@ async fn request_add_days_with_invalid_repeat() {
         .with_days(41)
         .set_repeat(5);
 
-    let response = client
-        .calculate_business_date(&request)
-        .await
-        .unwrap()
-        .unwrap_err();
+    let response = client.calculate_business_date(&request).await;
 
-    assert!(response.to_string() == "API Error: Error: The days parameter must have a single value if repeat parameter is enabled.");
+    assert!(matches!(response, Err(Error::Validation(e)) if e.argument == "repeat"));
 }
 
 /*
@@ -148,7 +144,7 @@ async fn request_add_days_with_invalid_repeat() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_add_days_with_country_and_state() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
 
     let request = BusinessDateRequest::new()
@@ -173,7 +169,7 @@ async fn request_add_days_with_country_and_state() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_add_days_with_filter() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
 
     let request = BusinessDateRequest::new()
@@ -199,3 +195,7 @@ async fn request_add_days_with_filter() {
     assert!(response.periods.iter().all(|x| x.weekdays.sat == 0));
     assert!(response.periods.iter().all(|x| x.weekdays.sun == 0));
 }
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -1,13 +1,13 @@
 use libtad_rs::models::{date_calculator::BusinessDaysFilterType, time::DateTime};
 use libtad_rs::service::date_calculator::BusinessDurationRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_duration() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
     let enddate = DateTime::from("2018-01-31");
 
@@ -38,7 +38,7 @@ async fn request_duration() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_duration_with_include() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
     let enddate = DateTime::from("2018-01-31");
 
@@ -70,7 +70,7 @@ async fn request_duration_with_include() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_duration_with_includelastdate() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
     let enddate = DateTime::from("2018-01-31");
 
@@ -97,7 +97,7 @@ async fn request_duration_with_includelastdate() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_duration_with_filter() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let startdate = DateTime::from("2017-12-01");
     let enddate = DateTime::from("2018-01-31");
 
@@ -128,3 +128,7 @@ async fn request_duration_with_filter() {
     assert!(response.period.weekdays.sat == 0);
     assert!(response.period.weekdays.sun == 0);
 }
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
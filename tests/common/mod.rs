@@ -0,0 +1,33 @@
+use libtad_rs::service::holidays::HolidaysResponse;
+
+/// JSON of a holiday with a date or exact time, a list of type labels and a list of states.
+pub fn holiday(uid: &str, country: &str, iso: &str, types: &str, states: &str) -> String {
+    let date = &iso[0..10];
+
+    format!(
+        r#"{{
+            "id": 1,
+            "uid": "{}",
+            "name": [{{ "lang": "en", "text": "Holiday" }}],
+            "date": {{ "iso": "{}", "datetime": {{ "year": {}, "month": {}, "day": {} }} }},
+            "url": "https://www.timeanddate.com/holidays/",
+            "country": {{ "id": "{}", "name": "{}" }},
+            "states": {},
+            "types": [{}]
+        }}"#,
+        uid,
+        iso,
+        &date[0..4],
+        date[5..7].trim_start_matches('0'),
+        date[8..10].trim_start_matches('0'),
+        country,
+        country,
+        states,
+        types
+    )
+}
+
+/// Holidays response with the given holidays.
+pub fn response(holidays: &[String]) -> HolidaysResponse {
+    serde_json::from_str(&format!(r#"{{ "holidays": [{}] }}"#, holidays.join(","))).unwrap()
+}
@@ -1,13 +1,13 @@
 use libtad_rs::models::time::DateTime;
 use libtad_rs::service::time::ConvertTimeRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn convert_time_without_toid() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let datetime = DateTime::from("2021-07-27T12:00:00");
 
     let request = ConvertTimeRequest::new()
@@ -25,7 +25,7 @@ async fn convert_time_without_toid() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn convert_time_with_toid() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let datetime = DateTime::from("2021-07-27T12:00:00");
 
     let request = ConvertTimeRequest::new()
@@ -47,7 +47,7 @@ async fn convert_time_with_toid() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn convert_time_with_timechange_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let datetime = DateTime::from("2021-07-27T12:00:00");
 
     let request = ConvertTimeRequest::new()
@@ -64,7 +64,7 @@ async fn convert_time_with_timechange_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn convert_time_without_timechange_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let datetime = DateTime::from("2021-07-27T12:00:00");
 
     let request = ConvertTimeRequest::new()
@@ -81,7 +81,7 @@ async fn convert_time_without_timechange_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn convert_time_with_timezone_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let datetime = DateTime::from("2021-07-27T12:00:00");
 
     let request = ConvertTimeRequest::new()
@@ -101,7 +101,7 @@ async fn convert_time_with_timezone_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn convert_time_without_timezone_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let datetime = DateTime::from("2021-07-27T12:00:00");
 
     let request = ConvertTimeRequest::new()
@@ -121,7 +121,7 @@ async fn convert_time_without_timezone_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn convert_time_with_radius() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
     let datetime = DateTime::from("2021-07-27T12:00:00");
 
     let request = ConvertTimeRequest::new()
@@ -134,3 +134,57 @@ async fn convert_time_with_radius() {
     assert!(response.locations[0].geo.name == "Oslo");
     assert!(response.locations[0].geo.country.name == "Norway");
 }
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_convert_between_places() {
+    let client = ServiceClient::with_credentials(credentials());
+    let datetime = chrono::NaiveDate::from_ymd_opt(2021, 4, 5)
+        .unwrap()
+        .and_hms_opt(16, 45, 2)
+        .unwrap();
+
+    let converted = client
+        .convert(datetime, "norway/oslo", "usa/chicago")
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    assert!(converted.offset().local_minus_utc() == -5 * 3600);
+    assert!(converted.naive_local().to_string() == "2021-04-05 09:45:02");
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_offset_of_place() {
+    let client = ServiceClient::with_credentials(credentials());
+    let winter = chrono::NaiveDate::from_ymd_opt(2021, 1, 5)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let summer = chrono::NaiveDate::from_ymd_opt(2021, 7, 5)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+
+    let winter = client
+        .offset_of("norway/oslo", winter)
+        .await
+        .unwrap()
+        .unwrap();
+    let summer = client
+        .offset_of("norway/oslo", summer)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(winter.unwrap().local_minus_utc() == 3600);
+    assert!(summer.unwrap().local_minus_utc() == 7200);
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -0,0 +1,117 @@
+use libtad_rs::{Credentials, CredentialsError};
+
+#[test]
+fn load_credentials_from_env() {
+    std::env::set_var("LIBTAD_TEST_ACCESS_KEY", "access");
+    std::env::set_var("LIBTAD_TEST_SECRET_KEY", "secret");
+
+    let credentials =
+        Credentials::from_env_vars("LIBTAD_TEST_ACCESS_KEY", "LIBTAD_TEST_SECRET_KEY").unwrap();
+
+    assert!(credentials.access_key() == "access");
+}
+
+#[test]
+fn missing_env_variable() {
+    let result = Credentials::from_env_vars("LIBTAD_TEST_MISSING", "LIBTAD_TEST_MISSING");
+
+    assert!(matches!(
+        result,
+        Err(CredentialsError::MissingVariable(ref name)) if name == "LIBTAD_TEST_MISSING"
+    ));
+}
+
+#[test]
+fn load_credentials_from_json_file() {
+    let path = std::env::temp_dir().join("libtad-credentials-test.json");
+    std::fs::write(
+        &path,
+        r#"{ "access_key": "access", "secret_key": "secret" }"#,
+    )
+    .unwrap();
+
+    let credentials = Credentials::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(credentials.access_key() == "access");
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn load_credentials_from_toml() {
+    let credentials = Credentials::from_toml(
+        r#"
+        access_key = "access"
+        secret_key = "secret"
+        "#,
+    )
+    .unwrap();
+
+    assert!(credentials.access_key() == "access");
+}
+
+#[test]
+fn invalid_config() {
+    let result = Credentials::from_json(r#"{ "access_key": "access" }"#);
+
+    assert!(matches!(result, Err(CredentialsError::Parse(_))));
+}
+
+#[test]
+fn parse_errors_do_not_leak_keys() {
+    let error = Credentials::from_json(r#"{ "access_key": "access", "secret_key": 987654321 }"#)
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(error.contains("line 1"));
+    assert!(!error.contains("987654321"));
+
+    let error =
+        Credentials::from_json(r#"{ "access_key": "access", "secret_key": "very-secret-key }"#)
+            .err()
+            .unwrap()
+            .to_string();
+
+    assert!(!error.contains("very-secret-key"));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_parse_errors_do_not_leak_keys() {
+    let error = Credentials::from_toml("access_key = \"access\"\nsecret_key = very-secret-key\n")
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(error.contains("line 2"));
+    assert!(!error.contains("very-secret-key"));
+}
+
+#[test]
+fn override_keys() {
+    let credentials = Credentials::new("access", "secret").set_access_key("other");
+
+    assert!(credentials.access_key() == "other");
+}
+
+#[test]
+fn load_credentials_from_provider() {
+    let provider = || Ok(Credentials::new("access", "secret"));
+    let credentials = Credentials::from_provider(&provider).unwrap();
+
+    assert!(credentials.access_key() == "access");
+
+    let failing = || Err(CredentialsError::Provider("vault is sealed".into()));
+
+    assert!(Credentials::from_provider(&failing).is_err());
+}
+
+#[test]
+fn debug_output_redacts_secret() {
+    let credentials = Credentials::new("access", "very-secret-key");
+    let debug = format!("{:?}", credentials);
+
+    assert!(debug.contains("access"));
+    assert!(!debug.contains("very-secret-key"));
+}
@@ -1,13 +1,13 @@
 use libtad_rs::models::time::DSTEntrySpecialType;
 use libtad_rs::service::time::DSTListRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_year(2016);
     let response = client
@@ -23,7 +23,7 @@ async fn request_dst_entries() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_for_specific_country() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_country("no");
 
@@ -42,7 +42,7 @@ async fn request_dst_entries_for_specific_country() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_for_specific_country_and_year() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_country("no").set_year(2014);
 
@@ -61,7 +61,7 @@ async fn request_dst_entries_for_specific_country_and_year() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_with_listplaces() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_listplaces(true);
 
@@ -81,7 +81,7 @@ async fn request_dst_entries_with_listplaces() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_without_listplaces() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_listplaces(false);
 
@@ -101,7 +101,7 @@ async fn request_dst_entries_without_listplaces() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_with_timechanges() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_timechanges(true);
 
@@ -118,7 +118,7 @@ async fn request_dst_entries_with_timechanges() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_without_timechanges() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_timechanges(false);
 
@@ -135,7 +135,7 @@ async fn request_dst_entries_without_timechanges() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_with_onlydst() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_onlydst(true).set_year(2014);
 
@@ -152,7 +152,7 @@ async fn request_dst_entries_with_onlydst() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_dst_entries_without_onlydst() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = DSTListRequest::new().set_onlydst(false).set_year(2014);
 
@@ -173,3 +173,20 @@ async fn request_dst_entries_without_onlydst() {
             > 0
     )
 }
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_observes_dst() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let norway = client.observes_dst("no", 2021).await.unwrap().unwrap();
+    let japan = client.observes_dst("jp", 2021).await.unwrap().unwrap();
+
+    assert!(norway);
+    assert!(!japan);
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "geojson")]
+
+use libtad_rs::geojson::ToGeoJson;
+use libtad_rs::service::{places::PlacesResponse, tides::TidesResponse};
+
+#[test]
+fn places_become_point_features() {
+    let response: PlacesResponse = serde_json::from_str(
+        r#"{
+            "places": [
+                {
+                    "id": 187,
+                    "urlid": "norway/oslo",
+                    "geo": {
+                        "name": "Oslo",
+                        "country": { "id": "no", "name": "Norway" },
+                        "latitude": 59.914,
+                        "longitude": 10.752
+                    }
+                },
+                {
+                    "id": 179,
+                    "urlid": "usa/new-york",
+                    "geo": {
+                        "name": "New York",
+                        "state": "New York",
+                        "country": { "id": "us", "name": "USA" },
+                        "latitude": "",
+                        "longitude": ""
+                    }
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let json = serde_json::to_value(response.to_geojson()).unwrap();
+
+    assert!(json["type"] == "FeatureCollection");
+    assert!(json["features"][0]["id"] == 187);
+    assert!(json["features"][0]["geometry"]["coordinates"] == serde_json::json!([10.752, 59.914]));
+    assert!(json["features"][0]["properties"]["urlid"] == "norway/oslo");
+    assert!(json["features"][1]["geometry"].is_null());
+    assert!(json["features"][1]["properties"]["state"] == "New York");
+}
+
+#[test]
+fn tide_stations_carry_matched_place() {
+    let response: TidesResponse = serde_json::from_str(
+        r#"{
+            "stations": [
+                {
+                    "source": {
+                        "name": "Stavanger",
+                        "latitude": 58.974,
+                        "longitude": 5.731,
+                        "type": "Subordinate Station",
+                        "distance": 12.5
+                    },
+                    "matchparam": "norway/sola",
+                    "result": []
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let collection = response.to_geojson();
+    let properties = collection.features[0].properties.as_ref().unwrap();
+
+    assert!(properties["matchparam"] == "norway/sola");
+    assert!(properties["type"] == "subordinate");
+    assert!(properties["distance"] == 12.5);
+}
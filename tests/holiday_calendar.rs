@@ -0,0 +1,113 @@
+mod common;
+
+use chrono::Weekday;
+use common::{holiday, response};
+use libtad_rs::models::time::DateTime;
+use libtad_rs::service::holidays::{HolidayCalendar, Office};
+
+fn calendar() -> HolidayCalendar {
+    let holidays = [
+        holiday("no-0517", "no", "2022-05-17", "", "null"),
+        holiday("no-0517", "no", "2022-05-17", "", "null"),
+        holiday("us-1226", "us", "2022-12-26", "", "null"),
+        holiday(
+            "us-0331",
+            "us",
+            "2022-03-31",
+            "",
+            r#"[{ "iso": "us-ca", "id": 5, "abbrev": "CA", "name": "California" }]"#,
+        ),
+        holiday("no-1226", "no", "2022-12-26", "", "null"),
+        holiday("no-1227", "no", "2022-12-27", "", "null"),
+        holiday("us-0102", "us", "2023-01-02", "", "null"),
+    ];
+
+    let mut calendar = HolidayCalendar::new()
+        .with_office(Office::new("no"))
+        .with_office(Office::new("us").with_state("us-ca"))
+        .with_office(Office::new("us").with_state("NY"));
+
+    calendar.insert(2022, response(&holidays));
+    calendar.insert(2023, response(&[]));
+    calendar
+}
+
+#[test]
+fn holidays_are_deduplicated_by_uid() {
+    let calendar = calendar();
+
+    assert!(calendar.holidays("no").len() == 3);
+    assert!(calendar.holidays("US").len() == 3);
+}
+
+#[test]
+fn closed_offices_respect_states() {
+    let calendar = calendar();
+
+    let closed = calendar.closed_offices(&DateTime::from("2022-03-31"));
+
+    assert!(closed.len() == 1);
+    assert!(closed[0].state.as_deref() == Some("us-ca"));
+
+    let closed = calendar.closed_offices(&DateTime::from("2022-05-17"));
+
+    assert!(closed.len() == 1);
+    assert!(closed[0].country == "no");
+}
+
+#[test]
+fn weekends_close_every_office() {
+    let calendar = calendar();
+
+    assert!(calendar.closed_offices(&DateTime::from("2022-05-14")).len() == 3);
+}
+
+#[test]
+fn weekends_are_set_per_office() {
+    let calendar = HolidayCalendar::new()
+        .with_office(Office::new("no"))
+        .with_office(Office::new("ae").with_weekend([Weekday::Fri, Weekday::Sat]));
+
+    let friday = calendar.closed_offices(&DateTime::from("2022-05-13"));
+    let sunday = calendar.closed_offices(&DateTime::from("2022-05-15"));
+
+    assert!(friday.len() == 1 && friday[0].country == "ae");
+    assert!(sunday.len() == 1 && sunday[0].country == "no");
+}
+
+#[test]
+fn next_common_working_day_spans_years() {
+    let calendar = calendar();
+
+    let next = calendar
+        .next_common_working_day(&DateTime::from("2022-12-23"))
+        .unwrap();
+
+    assert!(next == DateTime::from("2022-12-28"));
+
+    let next = calendar
+        .next_common_working_day(&DateTime::from("2022-12-30"))
+        .unwrap();
+
+    assert!(next == DateTime::from("2023-01-03"));
+
+    assert!(calendar
+        .next_common_working_day(&DateTime::from("2023-12-29"))
+        .is_none());
+}
+
+#[test]
+fn offices_in_new_countries_reload_years() {
+    let calendar = calendar().with_office(Office::new("us").with_state("TX"));
+
+    assert!(calendar.is_loaded(2022));
+
+    let calendar = calendar.with_office(Office::new("SE"));
+    let request = calendar.request(2022).unwrap();
+
+    assert!(!calendar.is_loaded(2022));
+    assert!(calendar.holidays("no").len() == 3);
+    assert!(serde_json::to_string(&request)
+        .unwrap()
+        .contains(r#""country":["no","se","us"]"#));
+}
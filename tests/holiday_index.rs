@@ -0,0 +1,163 @@
+mod common;
+
+use chrono::{FixedOffset, NaiveDate};
+use common::{holiday, response};
+use libtad_rs::models::holidays::HolidayType;
+use libtad_rs::service::holidays::{HolidayFilter, HolidayIndex};
+
+fn index() -> HolidayIndex {
+    let norway = response(&[
+        holiday(
+            "no-0517",
+            "no",
+            "2022-05-17",
+            r#""National holiday""#,
+            "null",
+        ),
+        holiday(
+            "no-0517",
+            "no",
+            "2022-05-17",
+            r#""National holiday""#,
+            "null",
+        ),
+        holiday(
+            "no-0526",
+            "no",
+            "2022-05-26",
+            r#""National holiday", "Christian""#,
+            "null",
+        ),
+        holiday("no-0508", "no", "2022-05-08", r#""Flag day""#, "null"),
+        holiday(
+            "no-0621",
+            "no",
+            "2022-06-21T11:13:40+02:00",
+            r#""Season""#,
+            "null",
+        ),
+    ]);
+
+    let us = response(&[
+        holiday(
+            "us-0530",
+            "us",
+            "2022-05-30",
+            r#""Federal Holiday""#,
+            "null",
+        ),
+        holiday(
+            "us-0331",
+            "us",
+            "2022-03-31",
+            r#""Local holiday""#,
+            r#"[{ "iso": "us-ca", "id": 5, "abbrev": "CA", "name": "California" }]"#,
+        ),
+    ]);
+
+    vec![norway, us].into_iter().collect()
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn responses_loaded_twice_are_indexed_once() {
+    let mut index = index();
+    let len = index.len();
+
+    index.insert(response(&[holiday(
+        "no-0517",
+        "no",
+        "2022-05-17",
+        r#""National holiday""#,
+        "null",
+    )]));
+
+    assert!(len == 6);
+    assert!(index.len() == len);
+}
+
+#[test]
+fn holidays_on_a_date() {
+    let index = index();
+    let norway = HolidayFilter::new().set_country("no");
+
+    assert!(index.is_holiday(date(2022, 5, 17), &norway));
+    assert!(!index.is_holiday(date(2022, 5, 17), &HolidayFilter::new().set_country("us")));
+    assert!(!index.is_holiday(date(2022, 5, 18), &norway));
+    assert!(index.on(date(2022, 5, 17), &HolidayFilter::new()).len() == 1);
+}
+
+#[test]
+fn holidays_in_a_week() {
+    let index = index();
+
+    let week = index.in_week(date(2022, 5, 25), &HolidayFilter::new());
+
+    assert!(week.len() == 1);
+    assert!(week[0].uid == "no-0526");
+}
+
+#[test]
+fn next_holiday_of_a_type() {
+    let index = index();
+    let federal = HolidayFilter::new().with_type(HolidayType::Federal);
+
+    let next = index.next(date(2022, 5, 17), &federal).unwrap();
+    assert!(next.uid == "no-0526");
+
+    let next = index.next(date(2022, 5, 26), &federal).unwrap();
+    assert!(next.uid == "us-0530");
+
+    let religious = HolidayFilter::new().with_type(HolidayType::Religious);
+    assert!(index.next(date(2022, 1, 1), &religious).unwrap().uid == "no-0526");
+    assert!(index.next(date(2022, 5, 30), &federal).is_none());
+}
+
+#[test]
+fn state_holidays_need_a_matching_state() {
+    let index = index();
+    let us = HolidayFilter::new().set_country("us");
+
+    assert!(index.is_holiday(date(2022, 3, 31), &us.clone().set_state("CA")));
+    assert!(!index.is_holiday(date(2022, 3, 31), &us.clone().set_state("us-ny")));
+    assert!(index.is_holiday(date(2022, 3, 31), &us));
+}
+
+#[test]
+fn exact_times_are_dated_in_an_offset() {
+    let index = index();
+    let seasons = HolidayFilter::new().with_type(HolidayType::Seasons);
+    let oslo = FixedOffset::east_opt(2 * 3600).unwrap();
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+
+    assert!(index.on(date(2022, 6, 21), &seasons).len() == 1);
+    assert!(index.on_in(date(2022, 6, 21), oslo, &seasons).len() == 1);
+    assert!(index.on_in(date(2022, 6, 21), tokyo, &seasons).len() == 1);
+
+    let hawaii = FixedOffset::west_opt(10 * 3600).unwrap();
+
+    assert!(index.on_in(date(2022, 6, 20), hawaii, &seasons).len() == 1);
+    assert!(index.on_in(date(2022, 6, 21), hawaii, &seasons).is_empty());
+}
+
+#[test]
+fn labels_map_to_holiday_types() {
+    assert!(HolidayType::from_label("National holiday") == Some(HolidayType::Federal));
+    assert!(HolidayType::from_label("Common local holiday") == Some(HolidayType::FederalLocal));
+    assert!(HolidayType::from_label("Local observance") == Some(HolidayType::Local2));
+    assert!(HolidayType::from_label("Orthodox") == Some(HolidayType::Orthodox));
+    assert!(HolidayType::from_label("State holiday") == Some(HolidayType::Local));
+    assert!(HolidayType::from_label("Half Day") == Some(HolidayType::HalfDay));
+    assert!(HolidayType::from_label("half-day") == Some(HolidayType::HalfDay));
+    assert!(HolidayType::from_label("Something else").is_none());
+    assert!(HolidayType::from_label("Half day holiday") == Some(HolidayType::HalfDay));
+    assert!(HolidayType::from_label("Fun") == Some(HolidayType::Fun));
+    assert!(HolidayType::from_label("International holiday").is_none());
+    assert!(HolidayType::from_label("Refund day").is_none());
+    assert!(HolidayType::from_label("Holiday").is_none());
+    assert!(HolidayType::All.includes(HolidayType::Seasons));
+    assert!(!HolidayType::All.includes(HolidayType::Fun));
+}
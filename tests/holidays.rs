@@ -1,13 +1,14 @@
 use libtad_models::holidays::HolidayType;
+use libtad_models::time::DateTime;
 use libtad_rs::service::holidays::HolidaysRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_holidays() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = HolidaysRequest::new().set_year(2014).with_country("us");
 
@@ -25,7 +26,7 @@ async fn request_holidays() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_holidays_filtered_by_type() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = HolidaysRequest::new()
         .with_country("us")
@@ -58,7 +59,7 @@ async fn request_holidays_filtered_by_type() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_holidays_without_time_zone_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = HolidaysRequest::new()
         .with_country("us")
@@ -76,7 +77,7 @@ async fn request_holidays_without_time_zone_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_holidays_with_time_zone_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = HolidaysRequest::new()
         .with_country("us")
@@ -89,3 +90,34 @@ async fn request_holidays_with_time_zone_information() {
     // Holidays contain timezone information
     assert!(response.holidays.iter().all(|x| x.date.timezone.is_some()));
 }
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_holidays_for_range() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let request = HolidaysRequest::new()
+        .with_country("no")
+        .set_range(DateTime::from("2021-12-01"), DateTime::from("2022-01-31"));
+
+    let response = client.get_holidays(&request).await.unwrap().unwrap();
+    let dates: Vec<_> = response
+        .holidays
+        .iter()
+        .map(|holiday| {
+            let date = holiday.date.datetime;
+            (date.year, date.month, date.day)
+        })
+        .collect();
+
+    assert!(dates.first().unwrap() >= &(2021, 12, 1));
+    assert!(dates.last().unwrap() <= &(2022, 1, 31));
+    assert!(dates.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(dates.iter().any(|date| date.0 == 2021));
+    assert!(dates.iter().any(|date| date.0 == 2022));
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
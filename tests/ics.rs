@@ -0,0 +1,145 @@
+#![cfg(feature = "ics")]
+
+use libtad_rs::ics::{parse_astronomy_days, parse_holidays, ICalendar};
+use libtad_rs::models::astronomy::AstronomyDayEventType;
+use libtad_rs::service::{astronomy::AstronomyResponse, holidays::HolidaysResponse};
+
+const HOLIDAYS: &str = r#"{
+    "holidays": [
+        {
+            "id": 2006,
+            "uid": "0007d600000007de",
+            "name": [
+                { "lang": "en", "text": "New Year's Day" },
+                { "lang": "de", "text": "Neujahr" }
+            ],
+            "oneliner": [{ "lang": "en", "text": "New Year's Day is the first day of the year, i.e. January 1, in the Gregorian calendar." }],
+            "date": { "iso": "2014-01-01", "datetime": { "year": 2014, "month": 1, "day": 1 } },
+            "url": "https://www.timeanddate.com/holidays/us/new-year-day",
+            "country": { "id": "us", "name": "United States" },
+            "types": ["Federal Holiday"]
+        },
+        {
+            "id": 2066,
+            "uid": "0008120000000811",
+            "name": [{ "lang": "en", "text": "March Equinox" }],
+            "date": {
+                "iso": "2021-03-20T05:37:28-04:00",
+                "datetime": { "year": 2021, "month": 3, "day": 20, "hour": 5, "minute": 37, "second": 28 },
+                "timezone": {
+                    "offset": "-04:00",
+                    "zoneabb": "EDT",
+                    "zonename": "Eastern Daylight Time",
+                    "zoneoffset": -18000,
+                    "zonedst": 3600,
+                    "zonetotaloffset": -14400
+                }
+            },
+            "url": "https://www.timeanddate.com/calendar/march-equinox.html",
+            "country": { "id": "us", "name": "United States" },
+            "types": ["Season"]
+        }
+    ]
+}"#;
+
+const ASTRONOMY: &str = r#"{
+    "locations": [
+        {
+            "id": "187",
+            "geo": { "name": "Oslo", "country": { "id": "no", "name": "Norway" } },
+            "matchparam": "norway/oslo",
+            "astronomy": {
+                "objects": [
+                    {
+                        "name": "sun",
+                        "days": [
+                            {
+                                "date": "2021-08-18",
+                                "events": [
+                                    { "type": "rise", "hour": 5, "min": 34, "sec": 12, "utctime": "2021-08-18T03:34:12" },
+                                    { "type": "set", "hour": 21, "min": 2, "sec": 55, "isotime": "2021-08-18T21:02:55+02:00" },
+                                    { "type": "meridian", "hour": 13, "min": 18, "sec": 30 }
+                                ]
+                            }
+                        ]
+                    }
+                ]
+            }
+        }
+    ]
+}"#;
+
+#[test]
+fn holidays_render_as_all_day_and_timed_events() {
+    let response: HolidaysResponse = serde_json::from_str(HOLIDAYS).unwrap();
+    let ics = ICalendar::new()
+        .set_lang("de")
+        .with_holidays(&response)
+        .to_string();
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("UID:0007d600000007de@timeanddate.com\r\n"));
+    assert!(ics.contains("DTSTART;VALUE=DATE:20140101\r\n"));
+    assert!(ics.contains("DTEND;VALUE=DATE:20140102\r\n"));
+    assert!(ics.contains("SUMMARY;LANGUAGE=de:Neujahr\r\n"));
+    assert!(ics.contains("DTSTART:20210320T093728Z\r\n"));
+    assert!(ics.lines().all(|l| l.len() <= 75));
+}
+
+#[test]
+fn calendars_are_deterministic() {
+    let response: HolidaysResponse = serde_json::from_str(HOLIDAYS).unwrap();
+    let render = || ICalendar::new().with_holidays(&response).to_string();
+
+    assert!(render() == render());
+    assert!(render().contains("DTSTAMP:19700101T000000Z\r\n"));
+}
+
+#[test]
+fn invalid_astronomy_dates_are_rejected() {
+    let ics = "BEGIN:VEVENT\r\nX-TAD-EVENT:rise\r\nX-TAD-DATE:2021818\r\nX-TAD-LOCALTIME:053412\r\nEND:VEVENT\r\n";
+
+    assert!(parse_astronomy_days(ics).is_err());
+    assert!(parse_astronomy_days(&ics.replace("2021818", "2021081é")).is_err());
+    assert!(parse_astronomy_days(&ics.replace("2021818", "20210230")).is_err());
+}
+
+#[test]
+fn holidays_round_trip() {
+    let response: HolidaysResponse = serde_json::from_str(HOLIDAYS).unwrap();
+    let ics = ICalendar::new().with_holidays(&response).to_string();
+
+    let holidays = parse_holidays(&ics).unwrap();
+
+    assert!(holidays.len() == 2);
+    assert!(holidays[0].uid == "0007d600000007de");
+    assert!(holidays[0].id == 2006);
+    assert!(holidays[0].name[0].text == "New Year's Day");
+    assert!(holidays[0].oneliner.as_ref().unwrap()[0]
+        .text
+        .starts_with("New Year's Day is"));
+    assert!(holidays[0].url.as_str() == "https://www.timeanddate.com/holidays/us/new-year-day");
+    assert!(holidays[0].country.as_ref().unwrap().id == "us");
+    assert!(holidays[0].types.as_ref().unwrap()[0] == "Federal Holiday");
+    assert!(holidays[1].date.datetime.hour == 5 && holidays[1].date.datetime.second == 28);
+}
+
+#[test]
+fn astronomy_events_round_trip() {
+    let response: AstronomyResponse = serde_json::from_str(ASTRONOMY).unwrap();
+    let ics = ICalendar::new().with_astronomy(&response).to_string();
+
+    assert!(ics.contains("DTSTART:20210818T033412Z\r\n"));
+    assert!(ics.contains("DTSTART:20210818T190255Z\r\n"));
+    assert!(ics.contains("UID:187-sun-20210818T053412-rise@timeanddate.com\r\n"));
+    assert!(!ics.contains("meridian"));
+
+    let days = parse_astronomy_days(&ics).unwrap();
+
+    assert!(days.len() == 1);
+    assert!(days[0].date.day == 18);
+    assert!(days[0].events.len() == 2);
+    assert!(days[0].events[0].r#type == AstronomyDayEventType::Rise);
+    assert!(days[0].events[0].utctime.unwrap().hour == 3);
+    assert!(days[0].events[1].hour == 21 && days[0].events[1].sec == 55);
+}
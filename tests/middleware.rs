@@ -0,0 +1,124 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use libtad_rs::middleware::{Middleware, Next, Request, Response};
+use libtad_rs::observer::{Call, Outcome};
+use libtad_rs::service::holidays::HolidaysRequest;
+use libtad_rs::{Error, ServiceClient};
+use maybe_async::maybe_async;
+use std::sync::{Arc, Mutex};
+
+/// Layer answering every request without sending it.
+#[derive(Clone, Default)]
+struct Fixture {
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+#[maybe_async]
+impl Middleware for Fixture {
+    async fn handle(&self, request: Request, _: Next<'_>) -> Result<Response, Error> {
+        self.requests.lock().unwrap().push(request);
+
+        Ok(Response::new(
+            br#"{"errors":["Invalid signature"]}"#.to_vec(),
+            Some(Utc.with_ymd_and_hms(2021, 4, 4, 17, 42, 21).unwrap()),
+        ))
+    }
+}
+
+/// Layer recording the order in which layers run.
+struct Named(&'static str, Arc<Mutex<Vec<&'static str>>>);
+
+#[maybe_async]
+impl Middleware for Named {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        self.1.lock().unwrap().push(self.0);
+        next.run(request).await
+    }
+}
+
+fn clock() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap()
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn layers_run_in_order() {
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let fixture = Fixture::default();
+
+    let client = ServiceClient::new("access".into(), "secret".into())
+        .with_layer(Named("first", order.clone()))
+        .with_layer(Named("second", order.clone()))
+        .with_layer(fixture.clone());
+
+    let request = HolidaysRequest::new().with_country("no").set_year(2021);
+    let result = client.get_holidays(&request).await;
+
+    assert!(*order.lock().unwrap() == ["first", "second"]);
+    assert!(matches!(result, Ok(Err(e)) if e.errors == ["Invalid signature"]));
+
+    let requests = fixture.requests.lock().unwrap();
+    assert!(requests.len() == 1);
+    assert!(requests[0].path == "holidays");
+    assert!(requests[0].query.as_deref() == Some("country=no&year=2021"));
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn skew_correction_retries_request() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let observed = calls.clone();
+    let fixture = Fixture::default();
+
+    let client = ServiceClient::new("access".into(), "secret".into())
+        .set_clock(clock)
+        .set_observer(move |call: &Call| observed.lock().unwrap().push(call.clone()))
+        .set_skew_correction(true)
+        .with_layer(fixture.clone());
+
+    let request = HolidaysRequest::new().with_country("no").set_year(2021);
+    let _ = client.get_holidays(&request).await;
+
+    let requests = fixture.requests.lock().unwrap();
+    assert!(requests.len() == 2);
+    assert!(requests[0].timestamp == clock());
+    assert!(requests[1].timestamp == clock() + Duration::hours(1));
+    assert!(client.skew() == Duration::hours(1));
+
+    let calls = calls.lock().unwrap();
+    assert!(calls.len() == 1);
+    assert!(calls[0].attempts == 2);
+    assert!(calls[0].outcome == Outcome::ApiError);
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn skew_correction_is_idempotent_and_reversible() {
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let fixture = Fixture::default();
+
+    // A second correction layer after "between" would retry without passing through it again.
+    let client = ServiceClient::new("access".into(), "secret".into())
+        .set_clock(clock)
+        .set_skew_correction(true)
+        .with_layer(Named("between", order.clone()))
+        .set_skew_correction(true)
+        .with_layer(fixture.clone());
+
+    let request = HolidaysRequest::new().with_country("no").set_year(2021);
+    let _ = client.get_holidays(&request).await;
+
+    assert!(*order.lock().unwrap() == ["between", "between"]);
+    assert!(fixture.requests.lock().unwrap().len() == 2);
+    assert!(client.skew() == Duration::hours(1));
+
+    let client = client.set_skew_correction(false);
+    let _ = client.get_holidays(&request).await;
+
+    let requests = fixture.requests.lock().unwrap();
+    assert!(requests.len() == 3);
+    assert!(requests[2].timestamp == clock());
+    assert!(client.skew() == Duration::zero());
+}
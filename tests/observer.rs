@@ -0,0 +1,55 @@
+use libtad_rs::observer::{Call, Outcome};
+use libtad_rs::service::holidays::HolidaysRequest;
+use libtad_rs::service::onthisday::OnThisDayRequest;
+use libtad_rs::{Error, ServiceClient};
+use maybe_async::maybe_async;
+use std::sync::{Arc, Mutex};
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn observer_is_notified() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let observed = calls.clone();
+
+    let client = ServiceClient::new("invalid".into(), "invalid".into())
+        .set_observer(move |call: &Call| observed.lock().unwrap().push(call.clone()));
+
+    let request = HolidaysRequest::new().with_country("no").set_year(2021);
+    let result = client.get_holidays(&request).await;
+
+    let calls = calls.lock().unwrap();
+    assert!(calls.len() == 1);
+
+    let call = &calls[0];
+    assert!(call.path == "holidays");
+    assert!(call.attempts == 1);
+    assert!(call.outcome.is_error());
+
+    match result {
+        Ok(Err(_)) => assert!(call.outcome == Outcome::ApiError && call.size.is_some()),
+        Err(_) => assert!(call.outcome != Outcome::ApiError && call.size.is_none()),
+        Ok(Ok(_)) => panic!("invalid credentials were accepted"),
+    }
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn observer_is_notified_of_invalid_requests() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let observed = calls.clone();
+
+    let client = ServiceClient::new("access".into(), "secret".into())
+        .set_observer(move |call: &Call| observed.lock().unwrap().push(call.clone()));
+
+    let request = OnThisDayRequest::new().set_day(3);
+    let result = client.get_events_on_this_day(&request).await;
+
+    assert!(matches!(result, Err(Error::Validation(e)) if e.argument == "day"));
+
+    let calls = calls.lock().unwrap();
+    assert!(calls.len() == 1);
+    assert!(calls[0].outcome == Outcome::Validation);
+    assert!(calls[0].size.is_none());
+}
@@ -1,13 +1,13 @@
 use libtad_rs::models::{onthisday::EventType, time::Time};
 use libtad_rs::service::onthisday::OnThisDayRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, Error, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_all() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = OnThisDayRequest::new().set_month(5).set_day(24);
     let response = client
@@ -24,20 +24,20 @@ async fn request_all() {
         .births
         .unwrap()
         .iter()
-        .all(|x| check_date(&x.birthdate.as_ref().unwrap())));
+        .all(|x| check_date(x.birthdate.as_ref().unwrap())));
 
     assert!(response
         .deaths
         .unwrap()
         .iter()
-        .all(|x| check_date(&x.deathdate.as_ref().unwrap())));
+        .all(|x| check_date(x.deathdate.as_ref().unwrap())));
 }
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_events_only() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = OnThisDayRequest::new()
         .set_month(5)
@@ -51,7 +51,7 @@ async fn request_events_only() {
         .unwrap();
 
     assert!(response.events.is_some());
-    assert!(response.events.unwrap().len() > 0);
+    assert!(!response.events.unwrap().is_empty());
 
     assert!(response.births.is_none());
     assert!(response.deaths.is_none());
@@ -61,7 +61,7 @@ async fn request_events_only() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_births_only() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = OnThisDayRequest::new()
         .set_month(5)
@@ -75,7 +75,7 @@ async fn request_births_only() {
         .unwrap();
 
     assert!(response.births.is_some());
-    assert!(response.births.unwrap().len() > 0);
+    assert!(!response.births.unwrap().is_empty());
 
     assert!(response.events.is_none());
     assert!(response.deaths.is_none());
@@ -85,7 +85,7 @@ async fn request_births_only() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_deaths_only() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = OnThisDayRequest::new()
         .set_month(5)
@@ -99,7 +99,7 @@ async fn request_deaths_only() {
         .unwrap();
 
     assert!(response.deaths.is_some());
-    assert!(response.deaths.unwrap().len() > 0);
+    assert!(!response.deaths.unwrap().is_empty());
 
     assert!(response.events.is_none());
     assert!(response.births.is_none());
@@ -109,31 +109,28 @@ async fn request_deaths_only() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn requesting_invalid_day() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::new("access".into(), "secret".into());
 
     let request = OnThisDayRequest::new().set_month(5).set_day(35);
 
-    let response = client.get_events_on_this_day(&request).await.unwrap();
+    let response = client.get_events_on_this_day(&request).await;
 
-    assert!(response.is_err());
-    assert!(
-        response.unwrap_err().to_string() == "API Error: Parameter 'day' must be between 1 and 31."
-    );
+    assert!(matches!(response, Err(Error::Validation(e)) if e.argument == "day"));
 }
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn requesting_invalid_month() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::new("access".into(), "secret".into());
 
     let request = OnThisDayRequest::new().set_month(0).set_day(24);
 
-    let response = client.get_events_on_this_day(&request).await.unwrap();
+    let response = client.get_events_on_this_day(&request).await;
 
-    assert!(response.is_err());
-    assert!(
-        response.unwrap_err().to_string()
-            == "API Error: Parameter 'month' must be between 1 and 12."
-    );
+    assert!(matches!(response, Err(Error::Validation(e)) if e.argument == "month"));
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
 }
@@ -1,12 +1,16 @@
-use libtad_rs::service::places::PlacesRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::models::{
+    places::{Place, PlaceId},
+    Distance,
+};
+use libtad_rs::service::places::{PlaceCatalog, PlaceQuery, PlaceResolver, PlacesRequest};
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_places() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = PlacesRequest::new();
     let response = client.get_places(&request).await.unwrap().unwrap();
@@ -27,7 +31,7 @@ async fn request_places() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_places_without_coordinates() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = PlacesRequest::new().set_geo(false);
     let response = client.get_places(&request).await.unwrap().unwrap();
@@ -43,3 +47,185 @@ async fn request_places_without_coordinates() {
     assert!(result.geo.latitude.is_none());
     assert!(result.geo.longitude.is_none());
 }
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn resolve_place_with_state_hint() {
+    let client = ServiceClient::with_credentials(credentials());
+    let mut resolver = PlaceResolver::new();
+
+    let query = PlaceQuery::new("Portland").set_state("Maine");
+    let resolution = client
+        .resolve_place(&mut resolver, &query)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(resolution.unwrap().place.geo.state.unwrap() == "Maine");
+    assert!(resolver.cached(&query).is_some());
+}
+
+fn places() -> Vec<Place> {
+    serde_json::from_str(
+        r#"[
+            {
+                "id": 202, "urlid": "usa/portland-or",
+                "geo": {
+                    "name": "Portland", "state": "Oregon",
+                    "country": { "id": "us", "name": "United States" },
+                    "latitude": 45.523, "longitude": -122.676
+                }
+            },
+            {
+                "id": 3429, "urlid": "usa/portland-me",
+                "geo": {
+                    "name": "Portland", "state": "Maine",
+                    "country": { "id": "us", "name": "United States" },
+                    "latitude": 43.661, "longitude": -70.255
+                }
+            },
+            {
+                "id": 1466, "urlid": "russia/saint-petersburg",
+                "geo": {
+                    "name": "Saint Petersburg", "state": "Saint Petersburg",
+                    "country": { "id": "ru", "name": "Russia" },
+                    "latitude": 59.894, "longitude": 30.264
+                }
+            }
+        ]"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn rank_places_by_hints() {
+    let oregon = PlaceResolver::rank(&PlaceQuery::new("portland"), places()).unwrap();
+    let maine = PlaceResolver::rank(&PlaceQuery::new("Portland, Maine"), places()).unwrap();
+    let near =
+        PlaceResolver::rank(&PlaceQuery::new("Portland").set_near(44.0, -70.0), places()).unwrap();
+
+    assert!(oregon.place.id == 202);
+    assert!(maine.place.id == 3429);
+    assert!(near.place.id == 3429);
+    assert!(maine.confidence > oregon.confidence);
+}
+
+#[test]
+fn resolve_fuzzy_names() {
+    let resolution = PlaceResolver::rank(
+        &PlaceQuery::new("st petersburg").set_country("ru"),
+        places(),
+    )
+    .unwrap();
+
+    assert!(resolution.place_id() == PlaceId::Numeric(1466));
+    assert!(String::from(resolution.place_id()) == "1466");
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_nearest_place() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let nearest = client
+        .nearest_place(59.914, 10.752, Some(Distance::from_km(50.0)))
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    assert!(nearest.location.geo.name == "Oslo");
+    assert!(nearest.distance.unwrap().km() < 50.0);
+}
+
+#[test]
+fn distance_between_coordinates() {
+    let places = places();
+    let saint_petersburg = &places.iter().find(|place| place.id == 1466).unwrap().geo;
+
+    let distance = saint_petersburg.distance_to(59.914, 10.752).unwrap();
+
+    assert!((distance.km() - 1084.0).abs() < 1.0);
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn load_place_catalog() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let catalog = client.load_place_catalog().await.unwrap().unwrap();
+
+    assert!(catalog.get(22).unwrap().urlid == "new-zealand/auckland");
+    assert!(catalog.search("auck", 1)[0].id == 22);
+}
+
+#[test]
+fn search_place_catalog() {
+    let catalog = PlaceCatalog::new(places());
+
+    let prefix = catalog.search("Port", 10);
+    let word = catalog.search("peters", 10);
+    let state = catalog.search("maine", 10);
+    let fuzzy = catalog.search("Portlnad", 10);
+
+    assert!(prefix.iter().map(|p| p.id).collect::<Vec<_>>() == [202, 3429]);
+    assert!(word[0].id == 1466);
+    assert!(state.len() == 1 && state[0].id == 3429);
+    assert!(fuzzy.len() == 2);
+    assert!(catalog.search("rus", 10)[0].id == 1466);
+    assert!(catalog.search("Oprtland", 10).is_empty());
+    assert!(catalog.search("", 10).is_empty());
+}
+
+#[test]
+fn nearest_places_in_catalog() {
+    let catalog = PlaceCatalog::new(places());
+
+    let nearest = catalog.nearest(43.0, -71.0, 2);
+
+    assert!(nearest.len() == 2);
+    assert!(nearest[0].0.id == 3429);
+    assert!(nearest[0].1.km() < nearest[1].1.km());
+}
+
+#[test]
+fn diff_place_catalogs() {
+    let old = PlaceCatalog::new(places());
+
+    let mut places = places();
+    places.remove(0);
+    places[0].urlid = "usa/portland-maine".into();
+    places.push(Place {
+        id: 187,
+        urlid: "norway/oslo".into(),
+        ..places[1].clone()
+    });
+    let new = PlaceCatalog::new(places);
+
+    let diff = old.diff(&new);
+
+    assert!(diff.added.len() == 1 && diff.added[0].id == 187);
+    assert!(diff.removed.len() == 1 && diff.removed[0].id == 202);
+    assert!(diff.changed.len() == 1 && diff.changed[0].1.urlid == "usa/portland-maine");
+    assert!(old.diff(&old).is_empty());
+}
+
+#[test]
+fn persist_place_catalog() {
+    let catalog = PlaceCatalog::new(places());
+
+    let mut snapshot = Vec::new();
+    catalog.to_writer(&mut snapshot).unwrap();
+    let restored = PlaceCatalog::from_reader(snapshot.as_slice()).unwrap();
+
+    assert!(restored.len() == 3);
+    assert!(restored.places() == catalog.places());
+    assert!(restored.search("saint", 1)[0].id == 1466);
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -0,0 +1,36 @@
+use chrono::{TimeZone, Utc};
+use libtad_rs::models::time::DateTime;
+use libtad_rs::service::{holidays::HolidaysRequest, time::TimeserviceRequest};
+use libtad_rs::ServiceClient;
+
+fn client() -> ServiceClient {
+    ServiceClient::new("access".into(), "secret".into())
+        .set_clock(|| Utc.with_ymd_and_hms(2021, 4, 4, 16, 42, 21).unwrap())
+}
+
+#[test]
+fn sign_holidays_url() {
+    let request = HolidaysRequest::new().with_country("no").set_year(2021);
+
+    let url = client().get_holidays_url(&request).unwrap();
+
+    assert!(
+        url.as_str()
+            == "https://api.xmltime.com/holidays?country=no&year=2021&accesskey=access\
+                &signature=AuwQjC5G5pGbmHN6pczY6%2B8Jw%2B4%3D&timestamp=2021-04-04T16%3A42%3A21\
+                &version=3"
+    );
+}
+
+#[test]
+fn signed_url_contains_no_secret() {
+    let request = TimeserviceRequest::new().set_placeid("norway/oslo");
+
+    let url = client().get_current_time_url(&request).unwrap();
+
+    assert!(url.path() == "/timeservice");
+    assert!(!url.as_str().contains("secret"));
+    assert!(url.query_pairs().any(
+        |(k, v)| k == "timestamp" && DateTime::from(v) == DateTime::from("2021-04-04T16:42:21")
+    ));
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "async-client")]
+
+use futures::StreamExt;
+use libtad_rs::models::{astronomy::AstronomyObjectType, time::DateTime};
+use libtad_rs::service::{
+    astronomy::AstroEventRequest, holidays::HolidaysRequest, tides::TidesRequest,
+};
+use libtad_rs::{Credentials, ServiceClient};
+
+#[tokio::test]
+async fn stream_astronomy_days_for_a_year() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let request = AstroEventRequest::new()
+        .with_object(AstronomyObjectType::Sun)
+        .with_placeid("norway/oslo")
+        .set_startdt(DateTime::from("2021-01-01"))
+        .set_enddt(DateTime::from("2021-12-31"));
+
+    let days: Vec<_> = client
+        .stream_astro_days(&request)
+        .map(|day| day.unwrap().unwrap())
+        .collect()
+        .await;
+
+    assert!(days.len() == 365);
+    assert!(days
+        .iter()
+        .all(|(_, object, _)| *object == AstronomyObjectType::Sun));
+    assert!(days[0].2.date == DateTime::from("2021-01-01"));
+    assert!(days[364].2.date == DateTime::from("2021-12-31"));
+}
+
+#[tokio::test]
+async fn stream_tides_for_multiple_stations() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let request = TidesRequest::new()
+        .with_placeid("norway/stavanger")
+        .with_placeid("norway/bergen")
+        .set_startdt(DateTime::from("2021-09-01T00:00:00"))
+        .set_enddt(DateTime::from("2021-09-04T00:00:00"));
+
+    let tides: Vec<_> = client
+        .stream_tidal_data(&request)
+        .map(|tide| tide.unwrap().unwrap())
+        .collect()
+        .await;
+
+    assert!(!tides.is_empty());
+    assert!(tides.iter().all(|(_, tide)| tide.time.datetime.month == 9));
+    assert!(tides
+        .iter()
+        .any(|(station, _)| station == "norway/stavanger"));
+    assert!(tides.iter().any(|(station, _)| station == "norway/bergen"));
+}
+
+#[tokio::test]
+async fn stream_holidays_for_multiple_years() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let request = HolidaysRequest::new()
+        .with_country("no")
+        .with_country("us")
+        .set_year(2021);
+
+    let holidays: Vec<_> = client
+        .stream_holidays(&request, 2020..=2022)
+        .map(|holiday| holiday.unwrap().unwrap())
+        .collect()
+        .await;
+
+    assert!(holidays.iter().any(|h| h.date.datetime.year == 2020));
+    assert!(holidays.iter().any(|h| h.date.datetime.year == 2022));
+}
+
+#[tokio::test]
+async fn stream_ends_after_first_error() {
+    let client = ServiceClient::new("invalid".into(), "invalid".into());
+
+    let request = HolidaysRequest::new()
+        .with_country("no")
+        .with_country("us")
+        .set_year(2021);
+
+    let results: Vec<_> = client
+        .stream_holidays(&request, 2020..=2022)
+        .collect()
+        .await;
+
+    assert!(results.len() == 1);
+    assert!(!matches!(results[0], Ok(Ok(_))));
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -0,0 +1,105 @@
+use libtad_rs::service::{
+    holidays::HolidaysResponse, onthisday::OnThisDayResponse, tides::TidesResponse,
+};
+use libtad_rs::tabular::Tabular;
+
+#[test]
+fn tides_are_flattened_per_tide() {
+    let response: TidesResponse = serde_json::from_str(
+        r#"{
+            "stations": [
+                {
+                    "source": {
+                        "name": "Stavanger",
+                        "latitude": 58.974,
+                        "longitude": 5.731,
+                        "type": "Reference Station",
+                        "distance": 0.0
+                    },
+                    "matchparam": "norway/stavanger",
+                    "result": [
+                        {
+                            "time": { "iso": "2021-09-08T02:36:00", "datetime": { "year": 2021, "month": 9, "day": 8 } },
+                            "amplitude": 0.31,
+                            "phase": "high"
+                        },
+                        {
+                            "time": { "iso": "2021-09-08T09:05:00", "datetime": { "year": 2021, "month": 9, "day": 8 } },
+                            "amplitude": -0.25,
+                            "phase": "low"
+                        }
+                    ]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let csv = response.to_csv();
+    let lines: Vec<_> = csv.lines().collect();
+
+    assert!(lines.len() == 3);
+    assert!(lines[0] == "station_name,station_type,station_latitude,station_longitude,station_distance,matchparam,time,amplitude,phase");
+    assert!(lines[1] == "Stavanger,reference,58.974,5.731,0.0,norway/stavanger,2021-09-08T02:36:00,0.31,high");
+
+    let ndjson = response.to_ndjson();
+    let rows: Vec<serde_json::Value> = ndjson
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+
+    assert!(rows.len() == 2);
+    assert!(rows[1]["phase"] == "low");
+    assert!(rows[1]["amplitude"] == -0.25);
+    assert!(ndjson.starts_with(r#"{"station_name":"Stavanger","station_type""#));
+}
+
+#[test]
+fn csv_fields_are_quoted() {
+    let response: HolidaysResponse = serde_json::from_str(
+        r#"{
+            "holidays": [
+                {
+                    "id": 1,
+                    "uid": "a",
+                    "name": [{ "lang": "en", "text": "Day of \"Quotes\", and commas" }],
+                    "date": { "iso": "2021-01-01", "datetime": { "year": 2021, "month": 1, "day": 1 } },
+                    "url": "https://www.timeanddate.com/holidays/",
+                    "types": ["National holiday", "Flag day"]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let csv = response.to_csv();
+    let row = csv.lines().nth(1).unwrap();
+
+    assert!(
+        row == r#"1,a,"Day of ""Quotes"", and commas",2021-01-01,,,https://www.timeanddate.com/holidays/,National holiday;Flag day,,,"#
+    );
+}
+
+#[test]
+fn onthisday_rows_are_tagged_by_kind() {
+    let response: OnThisDayResponse = serde_json::from_str(
+        r#"{
+            "births": [
+                {
+                    "id": 1,
+                    "name": { "first": "Ada", "last": "Lovelace" },
+                    "birthdate": { "iso": "1815-12-10", "datetime": { "year": 1815, "month": 12, "day": 10 } }
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let rows = response.rows();
+
+    assert!(rows.len() == 1);
+    assert!(rows[0].len() == OnThisDayResponse::COLUMNS.len());
+    assert!(rows[0][0] == "birth");
+    assert!(rows[0][2] == "Ada Lovelace");
+    assert!(rows[0][3] == "1815-12-10");
+}
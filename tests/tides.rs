@@ -1,14 +1,14 @@
-use libtad_rs::models::tides::TidalPhase;
-use libtad_rs::models::time::DateTime;
+use libtad_rs::models::tides::{StationInfo, StationType, TidalPhase, TideInterval};
+use libtad_rs::models::{time::DateTime, Distance};
 use libtad_rs::service::tides::TidesRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, Error, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_tidal_data() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TidesRequest::new()
         .with_placeid("norway/stavanger")
@@ -19,7 +19,7 @@ async fn request_tidal_data() {
 
     assert!(response.stations.len() == 1);
     assert!(response.stations[0].source.name == "Stavanger");
-    assert!(response.stations[0].source.r#type == "Reference Station");
+    assert!(response.stations[0].source.r#type == StationType::Reference);
     assert!(response.stations[0].matchparam == "norway/stavanger");
     assert!(response.stations[0]
         .result
@@ -35,7 +35,7 @@ async fn request_tidal_data() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_tidal_data_without_onlyhighlow() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TidesRequest::new()
         .with_placeid("norway/stavanger")
@@ -55,23 +55,23 @@ async fn request_tidal_data_without_onlyhighlow() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_invalid_end_date() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::new("access".into(), "secret".into());
 
     let request = TidesRequest::new()
         .with_placeid("norway/stavanger")
         .set_startdt(DateTime::from("2021-09-08T00:00:00"))
         .set_enddt(DateTime::from("2021-09-07T23:59:59"));
 
-    let response = client.get_tidal_data(&request).await.unwrap();
+    let response = client.get_tidal_data(&request).await;
 
-    assert!(response.is_err());
+    assert!(matches!(response, Err(Error::Validation(e)) if e.argument == "enddt"));
 }
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_subordinate_station() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TidesRequest::new()
         .with_placeid("norway/sola")
@@ -79,14 +79,14 @@ async fn request_subordinate_station() {
 
     let response = client.get_tidal_data(&request).await.unwrap().unwrap();
 
-    assert!(response.stations[0].source.r#type == "Subordinate Station");
+    assert!(response.stations[0].source.r#type == StationType::Subordinate);
 }
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_without_radius() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TidesRequest::new().with_placeid("4");
 
@@ -99,9 +99,11 @@ async fn request_without_radius() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_with_radius() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
-    let request = TidesRequest::new().with_placeid("4").set_radius(186);
+    let request = TidesRequest::new()
+        .with_placeid("4")
+        .set_radius(Distance::from_km(186.0));
 
     let response = client.get_tidal_data(&request).await.unwrap();
 
@@ -112,28 +114,85 @@ async fn request_with_radius() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_intervals() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let mut request = TidesRequest::new()
         .with_placeid("norway/stavanger")
         .set_onlyhighlow(false)
-        .set_interval(60);
+        .set_interval(TideInterval::Minutes60);
 
     let response_60 = client.get_tidal_data(&request).await.unwrap().unwrap();
     assert!(response_60.stations[0].result.len() == 24);
 
-    request = request.set_interval(30);
+    request = request.set_interval(TideInterval::Minutes30);
 
     let response_30 = client.get_tidal_data(&request).await.unwrap().unwrap();
     assert!(response_30.stations[0].result.len() == 48);
 
-    request = request.set_interval(15);
+    request = request.set_interval(TideInterval::Minutes15);
 
     let response_15 = client.get_tidal_data(&request).await.unwrap().unwrap();
     assert!(response_15.stations[0].result.len() == 96);
 
-    request = request.set_interval(5);
+    request = request.set_interval(TideInterval::Minutes5);
 
     let response_5 = client.get_tidal_data(&request).await.unwrap().unwrap();
     assert!(response_5.stations[0].result.len() == 288);
 }
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_tidal_data_for_a_month() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let request = TidesRequest::new()
+        .with_placeid("norway/stavanger")
+        .set_onlyhighlow(false)
+        .set_interval(TideInterval::Minutes60)
+        .set_startdt(DateTime::from("2021-09-01T00:00:00"))
+        .set_enddt(DateTime::from("2021-10-01T00:00:00"));
+
+    let response = client
+        .get_tidal_data_range(&request)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(response.stations.len() == 1);
+    assert!(response.stations[0].result.len() >= 30 * 24);
+}
+
+#[test]
+fn station_info_is_typed() {
+    let station: StationInfo = serde_json::from_str(
+        r#"{
+            "name": "Stavanger",
+            "latitude": 58.974,
+            "longitude": 5.731,
+            "type": "Harmonic Station",
+            "distance": 16.09344
+        }"#,
+    )
+    .unwrap();
+
+    assert!(station.r#type == StationType::Unknown("Harmonic Station".into()));
+    assert!((station.distance.miles() - 10.0).abs() < 1e-4);
+}
+
+#[test]
+fn interval_and_radius_are_sent_as_numbers() {
+    let client = ServiceClient::new("access".into(), "secret".into());
+    let request = TidesRequest::new()
+        .with_placeid("norway/stavanger")
+        .set_radius(Distance::from_miles(10.0))
+        .set_interval(TideInterval::Minutes15);
+
+    let url = client.get_tidal_data_url(&request).unwrap();
+
+    assert!(url.query().unwrap().contains("radius=16&interval=15"));
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -1,12 +1,13 @@
+use libtad_rs::models::time::{Time, TimeChange};
 use libtad_rs::service::time::TimeserviceRequest;
-use libtad_rs::ServiceClient;
+use libtad_rs::{Credentials, ServiceClient};
 use maybe_async::maybe_async;
 
 #[maybe_async]
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_for_numeric_id() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new().set_placeid("179");
 
@@ -19,7 +20,7 @@ async fn request_current_time_for_numeric_id() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_for_coordinates() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new().set_placeid("+59.914+10.752");
 
@@ -32,7 +33,7 @@ async fn request_current_time_for_coordinates() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_for_textual_id() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new().set_placeid("norway/oslo");
 
@@ -45,7 +46,7 @@ async fn request_current_time_for_textual_id() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_with_timechanges() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -60,7 +61,7 @@ async fn request_current_time_with_timechanges() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_without_timechanges() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -75,7 +76,7 @@ async fn request_current_time_without_timechanges() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_with_coordinates() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -93,7 +94,7 @@ async fn request_current_time_with_coordinates() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_without_coordinates() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -111,7 +112,7 @@ async fn request_current_time_without_coordinates() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_with_sunrise_sunset_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -126,7 +127,7 @@ async fn request_current_time_with_sunrise_sunset_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_without_sunrise_sunset_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -141,7 +142,7 @@ async fn request_current_time_without_sunrise_sunset_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_with_tz_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -159,7 +160,7 @@ async fn request_current_time_with_tz_information() {
 #[cfg_attr(feature = "async-client", tokio::test)]
 #[cfg_attr(feature = "sync-client", test)]
 async fn request_current_time_without_tz_information() {
-    let client = ServiceClient::new(env!("ACCESS_KEY").into(), env!("SECRET_KEY").into());
+    let client = ServiceClient::with_credentials(credentials());
 
     let request = TimeserviceRequest::new()
         .set_placeid("norway/oslo")
@@ -172,3 +173,110 @@ async fn request_current_time_without_tz_information() {
         .iter()
         .all(|x| x.time.as_ref().unwrap().timezone.is_none()));
 }
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_now_in_place() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let now = client
+        .now_in("norway/oslo")
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    assert!([3600, 7200].contains(&now.offset().local_minus_utc()));
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn request_next_dst_change() {
+    let client = ServiceClient::with_credentials(credentials());
+
+    let change = client
+        .next_dst_change("norway/oslo")
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    assert!(change.utc().unwrap() > chrono::Utc::now());
+    assert!([3600, 7200].contains(&change.new_offset().unwrap().local_minus_utc()));
+}
+
+#[test]
+fn time_with_offset() {
+    let time: Time = serde_json::from_str(
+        r#"{
+            "iso": "2011-06-08T09:18:16+02:00",
+            "datetime": { "year": 2011, "month": 6, "day": 8, "hour": 9, "minute": 18, "second": 16 }
+        }"#,
+    )
+    .unwrap();
+
+    let local = time.local().unwrap();
+
+    assert!(local.offset().local_minus_utc() == 7200);
+    assert!(local.naive_utc().to_string() == "2011-06-08 07:18:16");
+}
+
+#[test]
+fn time_without_offset_uses_time_zone() {
+    let time: Time = serde_json::from_str(
+        r#"{
+            "iso": "2011-06-08T09:18:16",
+            "datetime": { "year": 2011, "month": 6, "day": 8, "hour": 9, "minute": 18, "second": 16 },
+            "timezone": {
+                "offset": "+02:00",
+                "zoneabb": "CEST",
+                "zonename": "Central European Summer Time",
+                "zoneoffset": 3600,
+                "zonedst": 3600,
+                "zonetotaloffset": 7200
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let local = time.local().unwrap();
+
+    assert!(local.offset().local_minus_utc() == 7200);
+    assert!(local.naive_utc().to_string() == "2011-06-08 07:18:16");
+}
+
+#[test]
+fn time_without_offset_or_time_zone_is_unknown() {
+    let time: Time = serde_json::from_str(
+        r#"{
+            "iso": "2011-06-08T07:18:16",
+            "datetime": { "year": 2011, "month": 6, "day": 8, "hour": 7, "minute": 18, "second": 16 }
+        }"#,
+    )
+    .unwrap();
+
+    assert!(time.local().is_none());
+}
+
+#[test]
+fn time_change_is_typed() {
+    let change: TimeChange = serde_json::from_str(
+        r#"{
+            "newdst": 3600,
+            "newoffset": 7200,
+            "utctime": "2011-03-27T01:00:00",
+            "oldlocaltime": "2011-03-27T02:00:00",
+            "newlocaltime": "2011-03-27T03:00:00"
+        }"#,
+    )
+    .unwrap();
+
+    assert!(change.utc().unwrap().to_rfc3339() == "2011-03-27T01:00:00+00:00");
+    assert!(change.new_offset().unwrap().local_minus_utc() == 7200);
+}
+
+fn credentials() -> Credentials {
+    Credentials::from_env_vars("ACCESS_KEY", "SECRET_KEY").unwrap()
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "tracing")]
+
+use libtad_rs::service::holidays::HolidaysRequest;
+use libtad_rs::service::onthisday::OnThisDayRequest;
+use libtad_rs::ServiceClient;
+use maybe_async::maybe_async;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Clone, Default)]
+struct Recorder {
+    fields: Arc<Mutex<Vec<(String, String)>>>,
+    depth: Arc<Mutex<usize>>,
+    events: Arc<Mutex<Vec<usize>>>,
+}
+
+impl Visit for Recorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let value = format!("{:?}", value);
+        self.fields
+            .lock()
+            .unwrap()
+            .push((field.name().into(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        span.record(&mut self.clone());
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {
+        let depth = *self.depth.lock().unwrap();
+        self.events.lock().unwrap().push(depth);
+    }
+
+    fn enter(&self, _: &Id) {
+        *self.depth.lock().unwrap() += 1;
+    }
+
+    fn exit(&self, _: &Id) {
+        *self.depth.lock().unwrap() -= 1;
+    }
+}
+
+impl Recorder {
+    fn get(&self, name: &str) -> Option<String> {
+        let fields = self.fields.lock().unwrap();
+        fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.clone())
+    }
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn call_is_traced() {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let client = ServiceClient::new("access".into(), "secret".into());
+    let request = HolidaysRequest::new().with_country("no").set_year(2021);
+
+    let _ = client.get_holidays(&request).await;
+
+    assert!(recorder.get("path").unwrap() == "\"holidays\"");
+    assert!(recorder.get("query").unwrap() == "\"country=no&year=2021\"");
+    assert!(recorder.get("attempts").unwrap() == "1");
+    assert!(recorder.get("latency_ms").is_some());
+    assert!(recorder.get("api_error").is_some() || recorder.get("error").is_some());
+    assert!(!recorder
+        .fields
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(_, v)| v.contains("secret")));
+}
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn failures_are_logged_within_the_span() {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let client = ServiceClient::new("access".into(), "secret".into());
+    let request = OnThisDayRequest::new().set_day(3);
+
+    let _ = client.get_events_on_this_day(&request).await;
+
+    let events = recorder.events.lock().unwrap();
+    assert!(events.len() == 1);
+    assert!(events[0] > 0);
+}
@@ -0,0 +1,182 @@
+use libtad_rs::models::{
+    astronomy::AstronomyObjectType, date_calculator::BusinessDaysFilterType, time::DateTime,
+};
+use libtad_rs::service::{
+    astronomy::AstroEventRequest,
+    date_calculator::{BusinessDateRequest, BusinessDurationRequest},
+    holidays::HolidaysRequest,
+    onthisday::OnThisDayRequest,
+    places::PlacesRequest,
+    tides::TidesRequest,
+    time::DSTListRequest,
+    Validate,
+};
+use libtad_rs::{Error, ServiceClient};
+use maybe_async::maybe_async;
+
+#[maybe_async]
+#[cfg_attr(feature = "async-client", tokio::test)]
+#[cfg_attr(feature = "sync-client", test)]
+async fn invalid_request_is_not_sent() {
+    let client = ServiceClient::new("access".into(), "secret".into());
+    let request = TidesRequest::new()
+        .with_placeid("norway/oslo")
+        .set_startdt(DateTime::from("2021-08-20"))
+        .set_enddt(DateTime::from("2021-08-12"));
+
+    let result = client.get_tidal_data(&request).await;
+
+    assert!(matches!(result, Err(Error::Validation(e)) if e.argument == "enddt"));
+}
+
+#[test]
+fn onthisday_dates() {
+    assert!(OnThisDayRequest::new().validate().is_ok());
+    assert!(OnThisDayRequest::new().set_month(2).validate().is_ok());
+    assert!(OnThisDayRequest::new()
+        .set_month(2)
+        .set_day(29)
+        .validate()
+        .is_ok());
+
+    let missing_month = OnThisDayRequest::new().set_day(3).validate().unwrap_err();
+    assert!(missing_month.argument == "day");
+
+    assert!(OnThisDayRequest::new().set_month(13).validate().is_err());
+    assert!(OnThisDayRequest::new()
+        .set_month(4)
+        .set_day(31)
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn business_date_repeat() {
+    let request = BusinessDateRequest::new()
+        .set_placeid("norway/oslo")
+        .set_startdt(DateTime::from("2021-09-10"))
+        .set_repeat(3);
+
+    assert!(request.with_days(5).validate().is_ok());
+
+    let request = BusinessDateRequest::new()
+        .set_placeid("norway/oslo")
+        .set_startdt(DateTime::from("2021-09-10"))
+        .set_days(vec![5, 10])
+        .set_repeat(3);
+
+    assert!(request.validate().unwrap_err().argument == "repeat");
+}
+
+#[test]
+fn business_filter_all_requires_include() {
+    let request = BusinessDurationRequest::new()
+        .set_country("no")
+        .set_startdt(DateTime::from("2021-08-12"))
+        .set_enddt(DateTime::from("2021-08-20"))
+        .with_filter(BusinessDaysFilterType::All);
+
+    assert!(request.validate().unwrap_err().argument == "filter");
+    assert!(request.set_include(true).validate().is_ok());
+}
+
+#[test]
+fn end_before_start() {
+    let duration = BusinessDurationRequest::new()
+        .set_country("no")
+        .set_startdt(DateTime::from("2021-08-20"))
+        .set_enddt(DateTime::from("2021-08-12"));
+
+    assert!(duration.validate().unwrap_err().argument == "enddt");
+
+    let tides = TidesRequest::new()
+        .with_placeid("norway/oslo")
+        .set_startdt(DateTime::from("2021-08-20"))
+        .set_enddt(DateTime::from("2021-08-12"));
+
+    assert!(tides.validate().is_err());
+
+    let events = AstroEventRequest::new()
+        .with_object(AstronomyObjectType::Sun)
+        .with_placeid("norway/oslo")
+        .set_startdt(DateTime::from("2021-08-20"))
+        .set_enddt(DateTime::from("2021-08-12"));
+
+    assert!(events.validate().is_err());
+}
+
+#[test]
+fn holiday_ranges() {
+    let request = || HolidaysRequest::new().with_country("no");
+
+    assert!(request()
+        .set_range(DateTime::from("2021-06-01"), DateTime::from("2023-05-31"))
+        .validate()
+        .is_ok());
+
+    let reversed = request()
+        .set_range(DateTime::from("2023-05-31"), DateTime::from("2021-06-01"))
+        .validate()
+        .unwrap_err();
+    assert!(reversed.argument == "range");
+
+    assert!(request()
+        .set_range(DateTime::from("2021-06-01"), DateTime::from("70000-01-01"))
+        .validate()
+        .is_err());
+
+    let invalid = request()
+        .set_range(DateTime::from("2021-06-01"), DateTime::from("2023-02-30"))
+        .validate()
+        .unwrap_err();
+    assert!(invalid.argument == "range");
+
+    assert!(request()
+        .set_range(DateTime::from("2021-13-01"), DateTime::from("2023-05-31"))
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn places_query_limit() {
+    assert!(PlacesRequest::new()
+        .set_query("oslo")
+        .set_qlimit(20)
+        .validate()
+        .is_ok());
+    assert!(PlacesRequest::new().set_qlimit(20).validate().is_ok());
+
+    let zero = PlacesRequest::new().set_qlimit(0).validate().unwrap_err();
+    assert!(zero.argument == "qlimit");
+
+    assert!(PlacesRequest::new()
+        .set_query("oslo")
+        .set_qlimit(101)
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn dstlist_options() {
+    assert!(DSTListRequest::new()
+        .set_year(2016)
+        .set_country("no")
+        .set_timechanges(true)
+        .set_verbosetime(true)
+        .validate()
+        .is_ok());
+
+    let year = DSTListRequest::new().set_year(0).validate().unwrap_err();
+    assert!(year.argument == "year");
+
+    let country = DSTListRequest::new()
+        .set_country("nor")
+        .validate()
+        .unwrap_err();
+    assert!(country.argument == "country");
+
+    assert!(DSTListRequest::new()
+        .set_verbosetime(true)
+        .validate()
+        .is_ok());
+}
@@ -0,0 +1,108 @@
+use chrono::{Duration, NaiveTime, TimeZone, Utc, Weekday};
+use libtad_rs::models::{holidays::Holiday, time::TimeChange};
+use libtad_rs::service::date_calculator::WorkingHours;
+
+fn time(hour: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+}
+
+fn oslo_timechanges() -> Vec<TimeChange> {
+    serde_json::from_str(
+        r#"[
+            {
+                "newdst": 3600,
+                "newoffset": 7200,
+                "utctime": "2021-03-28T01:00:00",
+                "oldlocaltime": "2021-03-28T02:00:00",
+                "newlocaltime": "2021-03-28T03:00:00"
+            },
+            {
+                "newdst": null,
+                "newoffset": 3600,
+                "utctime": "2021-10-31T01:00:00",
+                "oldlocaltime": "2021-10-31T03:00:00",
+                "newlocaltime": "2021-10-31T02:00:00"
+            }
+        ]"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn business_duration_across_weekend() {
+    let hours = WorkingHours::new().with_weekdays(time(9), time(17));
+
+    let start = Utc.with_ymd_and_hms(2021, 9, 10, 15, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2021, 9, 14, 10, 0, 0).unwrap();
+
+    assert!(hours.business_duration(&start, &end) == Duration::hours(11));
+    assert!(hours.business_duration(&end, &start) == Duration::zero());
+}
+
+#[test]
+fn business_duration_respects_daylight_savings_time() {
+    let hours = WorkingHours::new()
+        .with_day(Weekday::Sun, time(0), time(6))
+        .with_timechanges(&oslo_timechanges());
+
+    let start = Utc.with_ymd_and_hms(2021, 3, 27, 12, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2021, 3, 29, 0, 0, 0).unwrap();
+
+    assert!(hours.business_duration(&start, &end) == Duration::hours(5));
+
+    let start = Utc.with_ymd_and_hms(2021, 10, 30, 12, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2021, 11, 1, 0, 0, 0).unwrap();
+
+    assert!(hours.business_duration(&start, &end) == Duration::hours(7));
+}
+
+#[test]
+fn holidays_close_or_shorten_days() {
+    let holidays: Vec<Holiday> = serde_json::from_str(
+        r#"[
+            {
+                "id": 1,
+                "uid": "a",
+                "name": [],
+                "date": { "iso": "2021-12-24", "datetime": { "year": 2021, "month": 12, "day": 24 } },
+                "url": "https://www.timeanddate.com/holidays/",
+                "types": ["Half-day holiday"]
+            },
+            {
+                "id": 2,
+                "uid": "b",
+                "name": [],
+                "date": { "iso": "2021-12-27", "datetime": { "year": 2021, "month": 12, "day": 27 } },
+                "url": "https://www.timeanddate.com/holidays/",
+                "types": ["National holiday"]
+            }
+        ]"#,
+    )
+    .unwrap();
+
+    let hours = WorkingHours::new()
+        .with_weekdays(time(9), time(17))
+        .with_holidays(&holidays);
+
+    let start = Utc.with_ymd_and_hms(2021, 12, 24, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2021, 12, 29, 0, 0, 0).unwrap();
+
+    assert!(hours.business_duration(&start, &end) == Duration::hours(11));
+}
+
+#[test]
+fn deadline_after_business_hours() {
+    let hours = WorkingHours::new()
+        .with_weekdays(time(9), time(17))
+        .with_timechanges(&oslo_timechanges());
+
+    let start = Utc.with_ymd_and_hms(2021, 9, 10, 13, 0, 0).unwrap();
+
+    assert!(
+        hours.deadline(&start, Duration::hours(4))
+            == Utc.with_ymd_and_hms(2021, 9, 13, 9, 0, 0).single()
+    );
+    assert!(WorkingHours::new()
+        .deadline(&start, Duration::hours(1))
+        .is_none());
+}